@@ -1,7 +1,39 @@
 use std::env;
 use std::fs::{self, File};
 use std::io::{BufRead, BufReader, Result, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+macro_rules! descs_template {
+    () => {
+        "
+/// The descriptions of all errors, each terminated by a NUL byte.
+const LINUX_ERROR_DESCS: &str = {0:?};
+
+/// The offsets of each description in `LINUX_ERROR_DESCS`, followed by the
+/// total length.
+const LINUX_ERROR_DESC_OFFSETS: [u16; {1}] = [{2}];
+"
+    };
+}
+
+const AS_STR_DESCS: &str = "        \
+        let desc = self.desc_with_nul();
+        desc.split_at(desc.len() - 1).0
+";
+
+const DESC_WITH_NUL_DESCS: &str = "        \
+        let start = LINUX_ERROR_DESC_OFFSETS[self.index()] as usize;
+        let end = LINUX_ERROR_DESC_OFFSETS[self.index() + 1] as usize;
+        LINUX_ERROR_DESCS.split_at(end).0.split_at(start).1
+";
+
+const AS_STR_NAMES: &str = "        \
+        self.name()
+";
+
+const DESC_WITH_NUL_NAMES: &str = "        \
+        self.name_with_nul()
+";
 
 macro_rules! template {
     () => {
@@ -10,30 +42,99 @@ macro_rules! template {
 
 /// Linux specific error codes defined in `errno.h`.
 #[repr(i32)]
+#[non_exhaustive]
+#[allow(non_camel_case_types)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = \"zerocopy\",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::TryFromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(
+    feature = \"bytemuck\",
+    derive(bytemuck::NoUninit, bytemuck::CheckedBitPattern)
+)]
+#[cfg_attr(feature = \"defmt\", derive(defmt::Format))]
 pub enum LinuxError {{
 {0}\
 }}
 
+/// All errors, in code order.
+const LINUX_ERRORS: [LinuxError; {1}] = [
+{2}];
+
+/// Maps each code to the index of its error in `LINUX_ERRORS` plus one, or to
+/// zero if there is no such error.
+const LINUX_ERROR_INDEX: [u8; {3}] = [{4}];
+
+/// The names of all errors, each terminated by a NUL byte.
+const LINUX_ERROR_NAMES: &str = {5:?};
+
+/// The offsets of each name in `LINUX_ERROR_NAMES`, followed by the total
+/// length.
+const LINUX_ERROR_NAME_OFFSETS: [u16; {6}] = [{7}];
+{8}
 impl TryFrom<i32> for LinuxError {{
     type Error = i32;
 
     fn try_from(value: i32) -> Result<Self, Self::Error> {{
-        use self::LinuxError::*;
-        match value {{
-{1}            _ => Err(value),
-        }}
+        LinuxError::from_code(value).ok_or(value)
     }}
 }}
 
 impl LinuxError {{
-    /// Returns the error description.
-    pub const fn as_str(&self) -> &'static str {{
-        use self::LinuxError::*;
-        match self {{
-{2}        }}
+    /// Returns the error with the given code, or `None` if there is no such
+    /// error.
+    pub const fn from_code(value: i32) -> Option<Self> {{
+        if value < 0 || value as usize >= LINUX_ERROR_INDEX.len() {{
+            return None;
+        }}
+        match LINUX_ERROR_INDEX[value as usize] {{
+            0 => None,
+            index => Some(LINUX_ERRORS[index as usize - 1]),
+        }}
+    }}
+
+    const fn index(self) -> usize {{
+        LINUX_ERROR_INDEX[self as usize] as usize - 1
+    }}
+
+    /// Returns the error with the given symbolic name, e.g. `\"ENOENT\"`, or
+    /// `None` if there is no such error.
+    pub fn from_name(name: &str) -> Option<Self> {{
+        LINUX_ERRORS.iter().copied().find(|e| e.name() == name)
+    }}
+
+    /// Returns the symbolic name of the error, e.g. `\"ENOENT\"`.
+    pub const fn name(self) -> &'static str {{
+        let name = self.name_with_nul();
+        name.split_at(name.len() - 1).0
     }}
 
+    /// Returns the symbolic name followed by a NUL byte.
+    pub(crate) const fn name_with_nul(self) -> &'static str {{
+        let start = LINUX_ERROR_NAME_OFFSETS[self.index()] as usize;
+        let end = LINUX_ERROR_NAME_OFFSETS[self.index() + 1] as usize;
+        LINUX_ERROR_NAMES.split_at(end).0.split_at(start).1
+    }}
+
+    /// Returns the built-in English error description. See
+    /// [`message`](LinuxError::message) for the localized one.
+    ///
+    /// With the `no-descriptions` feature, this is the same as
+    /// [`name`](LinuxError::name).
+    pub const fn as_str(&self) -> &'static str {{
+{9}    }}
+
+    /// Returns the error description followed by a NUL byte.
+    #[cfg_attr(not(feature = \"ffi\"), allow(dead_code))]
+    pub(crate) const fn desc_with_nul(self) -> &'static str {{
+{10}    }}
+
     /// Returns the error code value in `i32`.
     pub const fn code(self) -> i32 {{
         self as i32
@@ -43,16 +144,202 @@ impl LinuxError {{
     };
 }
 
+macro_rules! kind_template {
+    () => {
+        "\
+// Generated by build.rs, DO NOT edit
+
+/// The error kind type used by ArceOS.
+///
+/// Similar to [`std::io::ErrorKind`].
+///
+/// [`std::io::ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
+#[repr(i32)]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, EnumCount)]
+#[cfg_attr(
+    feature = \"zerocopy\",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::TryFromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(
+    feature = \"bytemuck\",
+    derive(bytemuck::NoUninit, bytemuck::CheckedBitPattern)
+)]
+#[cfg_attr(feature = \"defmt\", derive(defmt::Format))]
+pub enum AxErrorKind {{
+{0}\
+}}
+
+/// All error kinds, in code order.
+const AX_ERROR_KINDS: [AxErrorKind; AxErrorKind::COUNT] = [
+{1}];
+
+/// The names of all error kinds, in code order.
+const AX_ERROR_KIND_NAMES: [&str; AxErrorKind::COUNT] = [
+{2}];
+{3}
+/// The corresponding `LinuxError` of each error kind, in code order.
+const AX_ERROR_KIND_LINUX: [LinuxError; AxErrorKind::COUNT] = [
+{4}];
+
+/// Maps each `LinuxError` code to the code of the error kind it converts to,
+/// or to zero if there is no such kind.
+const LINUX_ERROR_KINDS: [u8; {5}] = [{6}];
+
+#[allow(non_upper_case_globals)]
+impl AxError {{
+{7}\
+}}
+"
+    };
+}
+
+const KIND_DESCS_TEMPLATE: &str = "
+/// The descriptions of all error kinds, in code order.
+const AX_ERROR_KIND_DESCS: [&str; AxErrorKind::COUNT] = [
+";
+
+macro_rules! code_template {
+    () => {
+        "\
+// Generated by build.rs, DO NOT edit
+
+/// The valid codes of an [`AxError`]: the negated codes of all [`LinuxError`]s
+/// and the codes of all [`AxErrorKind`]s, in ascending order.
+#[repr(i32)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = \"zerocopy\",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::TryFromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+enum AxErrorCode {{
+{0}\
+}}
+
+impl AxErrorCode {{
+    /// Returns the code of an error kind.
+    const fn from_kind(kind: AxErrorKind) -> Self {{
+        match kind {{
+{1}        }}
+    }}
+
+    /// Returns the negated code of a Linux error.
+    const fn from_linux(e: LinuxError) -> Self {{
+        match e {{
+{2}        }}
+    }}
+
+    /// Returns the code with the given value, or `None` if it is not valid.
+    const fn from_code(value: i32) -> Option<Self> {{
+        Some(match value {{
+{3}            _ => return None,
+        }})
+    }}
+}}
+"
+    };
+}
+
+macro_rules! header_template {
+    () => {
+        "\
+/* Generated by axerrno, DO NOT edit */
+
+#ifndef AXERRNO_H
+#define AXERRNO_H
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+/* Error kinds, with the same values as `AxErrorKind::code()`. */
+enum ax_error_kind {{
+{0}\
+}};
+
+/* Returns the description of an error number, \"Success\" for 0, or
+   \"Unknown error\". */
+const char *ax_strerror(int errnum);
+
+/* Returns the symbolic name of an error number, or NULL. */
+const char *ax_errno_name(int errnum);
+
+/* Returns the error number with a symbolic name, or 0. */
+int ax_errno_from_name(const char *name);
+
+/* Returns the error number corresponding to an `enum ax_error_kind`, or 0. */
+int ax_kind_to_errno(int kind);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif /* AXERRNO_H */
+"
+    };
+}
+
+#[path = "src/table/format.rs"]
+mod format;
+
 fn main() {
-    let out_dir = env::var_os("OUT_DIR").unwrap();
-    gen_linux_errno(&Path::new(&out_dir).join("linux_errno.rs")).unwrap();
+    for path in [
+        "build.rs",
+        "src/errno.h",
+        "src/error_kinds.txt",
+        "src/table/format.rs",
+    ] {
+        println!("cargo:rerun-if-changed={path}");
+    }
+    println!("cargo:rerun-if-env-changed=AXERRNO_TABLE_DIR");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let errnos = parse_errno_h().unwrap();
+    let kinds = parse_error_kinds(&errnos).unwrap();
+    gen_linux_errno(&errnos, &out_dir.join("linux_errno.rs")).unwrap();
+    gen_ax_error_kind(&errnos, &kinds, &out_dir.join("ax_error_kind.rs")).unwrap();
+    gen_ax_error_code(&errnos, &kinds, &out_dir.join("ax_error_code.rs")).unwrap();
+    if env::var_os("CARGO_FEATURE_FFI").is_some() {
+        gen_c_header(&kinds, &out_dir.join("axerrno.h")).unwrap();
+        println!("cargo:include={}", out_dir.display());
+    }
+    if let Some(dir) = env::var_os("AXERRNO_TABLE_DIR") {
+        gen_tables(&errnos, &kinds, Path::new(&dir)).unwrap();
+    }
 }
 
-fn gen_linux_errno(dest_path: &Path) -> Result<()> {
-    let mut enum_define = Vec::new();
-    let mut try_from_i32 = Vec::new();
-    let mut detail_info = Vec::new();
+/// An error number defined in `errno.h`.
+struct Errno {
+    name: String,
+    code: i32,
+    description: String,
+}
 
+/// An error kind defined in `error_kinds.txt`.
+struct ErrorKind {
+    name: String,
+    code: i32,
+    description: String,
+    doc: Vec<String>,
+    /// The name of the corresponding `LinuxError`.
+    linux: String,
+    /// Whether the `LinuxError` converts back to this kind.
+    from_linux: bool,
+}
+
+fn parse_errno_h() -> Result<Vec<Errno>> {
+    let mut errnos = Vec::new();
     let file = File::open("src/errno.h")?;
     for line in BufReader::new(file).lines().map_while(Result::ok) {
         if line.starts_with("#define") {
@@ -64,23 +351,315 @@ fn gen_linux_errno(dest_path: &Path) -> Result<()> {
                     } else {
                         format!("Error number {num}")
                     };
-                    writeln!(enum_define, "    /// {description}\n    {name} = {num},")?;
-                    writeln!(try_from_i32, "            {num} => Ok({name}),")?;
-                    writeln!(detail_info, "            {name} => \"{description}\",")?;
+                    errnos.push(Errno {
+                        name: name.into(),
+                        code: num.parse().expect("invalid error code"),
+                        description,
+                    });
                 }
             }
         }
     }
+    Ok(errnos)
+}
+
+fn gen_linux_errno(errnos: &[Errno], dest_path: &Path) -> Result<()> {
+    let mut enum_define = Vec::new();
+    let mut errors = Vec::new();
+    let mut index = Vec::new();
+    let mut names = String::new();
+    let mut name_offsets = vec![0];
+    let mut descs = String::new();
+    let mut desc_offsets = vec![0];
+
+    for Errno {
+        name,
+        code,
+        description,
+    } in errnos
+    {
+        writeln!(enum_define, "    /// {description}\n    {name} = {code},")?;
+        writeln!(errors, "    LinuxError::{name},")?;
+        let code = *code as usize;
+        if index.len() <= code {
+            index.resize(code + 1, 0);
+        }
+        index[code] = u8::try_from(name_offsets.len()).expect("too many errors");
+        names.push_str(name);
+        names.push('\0');
+        name_offsets.push(u16::try_from(names.len()).expect("names too long"));
+        descs.push_str(description);
+        descs.push('\0');
+        desc_offsets.push(u16::try_from(descs.len()).expect("descriptions too long"));
+    }
+
+    let join = |values: &[_]| {
+        values
+            .iter()
+            .map(|v: &u16| v.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let index = index.into_iter().map(u16::from).collect::<Vec<_>>();
+    let (descs, as_str, desc_with_nul) = if env::var_os("CARGO_FEATURE_NO_DESCRIPTIONS").is_some() {
+        (String::new(), AS_STR_NAMES, DESC_WITH_NUL_NAMES)
+    } else {
+        let descs = format!(
+            descs_template!(),
+            descs,
+            desc_offsets.len(),
+            join(&desc_offsets)
+        );
+        (descs, AS_STR_DESCS, DESC_WITH_NUL_DESCS)
+    };
 
     fs::write(
         dest_path,
         format!(
             template!(),
             String::from_utf8_lossy(&enum_define),
-            String::from_utf8_lossy(&try_from_i32),
-            String::from_utf8_lossy(&detail_info)
+            name_offsets.len() - 1,
+            String::from_utf8_lossy(&errors),
+            index.len(),
+            join(&index),
+            names,
+            name_offsets.len(),
+            join(&name_offsets),
+            descs,
+            as_str,
+            desc_with_nul,
         ),
     )?;
 
     Ok(())
 }
+
+/// Reports a malformed line of `error_kinds.txt`.
+fn invalid_error_kind(line: usize, msg: &str) -> ! {
+    panic!("src/error_kinds.txt:{line}: {msg}")
+}
+
+fn parse_error_kinds(errnos: &[Errno]) -> Result<Vec<ErrorKind>> {
+    let mut kinds = Vec::<ErrorKind>::new();
+    let mut doc = Vec::new();
+    let file = File::open("src/error_kinds.txt")?;
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        let line_no = i + 1;
+        if line.is_empty() || line == "#" || line.starts_with("# ") {
+            continue;
+        }
+        if line.starts_with("///") {
+            doc.push(line);
+            continue;
+        }
+
+        let (fields, description) = line
+            .split_once(" \"")
+            .and_then(|(fields, rest)| Some((fields, rest.strip_suffix('"')?)))
+            .unwrap_or_else(|| {
+                invalid_error_kind(line_no, "expected `Name = code -> ERRNO \"Description\"`")
+            });
+        let [name, "=", code, arrow, linux] = fields.split_whitespace().collect::<Vec<_>>()[..]
+        else {
+            invalid_error_kind(line_no, "expected `Name = code -> ERRNO \"Description\"`");
+        };
+        let from_linux = match arrow {
+            "->" => false,
+            "<->" => true,
+            _ => invalid_error_kind(line_no, "expected `->` or `<->`"),
+        };
+        let code = code
+            .parse()
+            .unwrap_or_else(|_| invalid_error_kind(line_no, "invalid error code"));
+        if code != kinds.len() as i32 + 1 {
+            invalid_error_kind(line_no, "error codes must start at 1 and be consecutive");
+        }
+        if doc.is_empty() {
+            invalid_error_kind(line_no, "missing doc comment");
+        }
+        if !errnos.iter().any(|e| e.name == linux) {
+            invalid_error_kind(line_no, "unknown `LinuxError`");
+        }
+        if from_linux && kinds.iter().any(|k| k.from_linux && k.linux == linux) {
+            invalid_error_kind(
+                line_no,
+                "`LinuxError` already converts back to another kind",
+            );
+        }
+        kinds.push(ErrorKind {
+            name: name.into(),
+            code,
+            description: description.into(),
+            doc: std::mem::take(&mut doc),
+            linux: linux.into(),
+            from_linux,
+        });
+    }
+    Ok(kinds)
+}
+
+fn gen_ax_error_kind(errnos: &[Errno], kinds: &[ErrorKind], dest_path: &Path) -> Result<()> {
+    let mut enum_define = String::new();
+    let mut all_kinds = String::new();
+    let mut names = String::new();
+    let mut descs = String::new();
+    let mut linux = String::new();
+    let mut from_linux = Vec::new();
+    let mut consts = String::new();
+
+    for kind in kinds {
+        let name = &kind.name;
+        for line in &kind.doc {
+            enum_define.push_str(&format!("    {line}\n"));
+        }
+        enum_define.push_str(&format!("    {name} = {},\n", kind.code));
+        all_kinds.push_str(&format!("    AxErrorKind::{name},\n"));
+        names.push_str(&format!("    {name:?},\n"));
+        descs.push_str(&format!("    {:?},\n", kind.description));
+        linux.push_str(&format!("    LinuxError::{},\n", kind.linux));
+        if kind.from_linux {
+            let code = errnos.iter().find(|e| e.name == kind.linux).unwrap().code as usize;
+            if from_linux.len() <= code {
+                from_linux.resize(code + 1, 0);
+            }
+            from_linux[code] = u8::try_from(kind.code).expect("too many error kinds");
+        }
+        consts.push_str(&format!(
+            "    /// An [`AxError`] with kind [`AxErrorKind::{name}`].\n    \
+             pub const {name}: Self = Self::from_kind(AxErrorKind::{name});\n"
+        ));
+    }
+
+    let descs = if env::var_os("CARGO_FEATURE_NO_DESCRIPTIONS").is_some() {
+        String::new()
+    } else {
+        format!("{KIND_DESCS_TEMPLATE}{descs}];\n")
+    };
+    let from_linux_len = from_linux.len();
+    let from_linux = from_linux
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    fs::write(
+        dest_path,
+        format!(
+            kind_template!(),
+            enum_define, all_kinds, names, descs, linux, from_linux_len, from_linux, consts,
+        ),
+    )
+}
+
+fn gen_ax_error_code(errnos: &[Errno], kinds: &[ErrorKind], dest_path: &Path) -> Result<()> {
+    let mut codes = errnos
+        .iter()
+        .map(|e| (&e.name, -e.code))
+        .chain(kinds.iter().map(|kind| (&kind.name, kind.code)))
+        .collect::<Vec<_>>();
+    codes.sort_by_key(|&(_, code)| code);
+
+    let mut enum_define = String::new();
+    let mut from_code = String::new();
+    for (name, code) in codes {
+        enum_define.push_str(&format!("    {name} = {code},\n"));
+        from_code.push_str(&format!("            {code} => Self::{name},\n"));
+    }
+    let mut from_kind = String::new();
+    for ErrorKind { name, .. } in kinds {
+        from_kind.push_str(&format!(
+            "            AxErrorKind::{name} => Self::{name},\n"
+        ));
+    }
+    let mut from_linux = String::new();
+    for Errno { name, .. } in errnos {
+        from_linux.push_str(&format!(
+            "            LinuxError::{name} => Self::{name},\n"
+        ));
+    }
+
+    fs::write(
+        dest_path,
+        format!(
+            code_template!(),
+            enum_define, from_kind, from_linux, from_code
+        ),
+    )
+}
+
+/// Converts a `CamelCase` name into `SCREAMING_SNAKE_CASE`.
+fn screaming_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let word_start = i > 0
+            && c.is_ascii_uppercase()
+            && (chars[i - 1].is_ascii_lowercase()
+                || chars.get(i + 1).is_some_and(char::is_ascii_lowercase));
+        if word_start {
+            result.push('_');
+        }
+        result.push(c.to_ascii_uppercase());
+    }
+    result
+}
+
+fn gen_c_header(kinds: &[ErrorKind], dest_path: &Path) -> Result<()> {
+    let mut enum_define = String::new();
+    for kind in kinds {
+        let name = screaming_snake_case(&kind.name);
+        enum_define.push_str(&format!("    AX_{name} = {},\n", kind.code));
+    }
+
+    fs::write(dest_path, format!(header_template!(), enum_define))
+}
+
+fn gen_tables(errnos: &[Errno], kinds: &[ErrorKind], dir: &Path) -> Result<()> {
+    use format::{Row, Table, TableFormat};
+
+    let errno_code = |name: &str| errnos.iter().find(|e| e.name == name).unwrap().code;
+    let descriptions = env::var_os("CARGO_FEATURE_NO_DESCRIPTIONS").is_none();
+
+    fs::create_dir_all(dir)?;
+    for format in TableFormat::ALL {
+        let mut kind_rows = kinds.iter().map(|kind| Row {
+            code: kind.code,
+            name: &kind.name,
+            description: descriptions.then_some(kind.description.as_str()),
+            mapped: Some((errno_code(&kind.linux), &kind.linux)),
+        });
+        let mut linux_rows = errnos.iter().map(|e| Row {
+            code: e.code,
+            name: &e.name,
+            description: descriptions.then_some(e.description.as_str()),
+            mapped: kinds
+                .iter()
+                .find(|kind| kind.from_linux && kind.linux == e.name)
+                .map(|kind| (kind.code, kind.name.as_str())),
+        });
+        let mut table = String::new();
+        format::write_tables(
+            &mut table,
+            format,
+            [
+                Table {
+                    name: "AxErrorKind",
+                    key: "ax_error_kinds",
+                    mapped_name: "LinuxError",
+                    mapped_key: "linux_error",
+                    rows: &mut kind_rows,
+                },
+                Table {
+                    name: "LinuxError",
+                    key: "linux_errors",
+                    mapped_name: "AxErrorKind",
+                    mapped_key: "ax_error_kind",
+                    rows: &mut linux_rows,
+                },
+            ],
+        )
+        .expect("failed to format the error tables");
+        fs::write(dir.join(format!("axerrno.{}", format.extension())), table)?;
+    }
+    Ok(())
+}
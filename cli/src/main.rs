@@ -0,0 +1,243 @@
+//! Looks up [`AxErrorKind`]s and [`LinuxError`]s by code or name.
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use axerrno::{AxError, AxErrorKind, LinuxError};
+
+const USAGE: &str = "\
+Usage: axerrno [--] <CODE|NAME>...
+       axerrno --list
+       axerrno --filter
+
+Looks up error codes and names, e.g. `axerrno 2`, `axerrno -- -110`,
+`axerrno ENOENT` or `axerrno NotFound`.
+
+Options:
+  -l, --list    List all error kinds and error numbers
+  -f, --filter  Copy stdin to stdout, annotating negative error numbers
+  -h, --help    Print this help
+";
+
+fn print_linux(out: &mut impl Write, e: LinuxError) -> io::Result<()> {
+    writeln!(
+        out,
+        "LinuxError::{} = {}: {}",
+        e.name(),
+        e.code(),
+        e.message()
+    )?;
+    writeln!(out, "  AxError code: {}", AxError::from(e).code())?;
+    match AxErrorKind::try_from(e) {
+        Ok(kind) => writeln!(out, "  -> AxErrorKind::{} = {}", kind.name(), kind.code()),
+        Err(_) => writeln!(out, "  -> no AxErrorKind"),
+    }
+}
+
+fn print_kind(out: &mut impl Write, kind: AxErrorKind) -> io::Result<()> {
+    writeln!(
+        out,
+        "AxErrorKind::{} = {}: {}",
+        kind.name(),
+        kind.code(),
+        kind.message()
+    )?;
+    writeln!(out, "  AxError code: {}", AxError::from(kind).code())?;
+    let e = kind.to_linux();
+    writeln!(out, "  -> LinuxError::{} = {}", e.name(), e.code())
+}
+
+/// Prints every error matching `arg`, returning `false` if there is none.
+fn lookup(out: &mut impl Write, arg: &str) -> io::Result<bool> {
+    let Ok(code) = arg.parse::<i32>() else {
+        if let Some(kind) = AxErrorKind::from_name(arg) {
+            print_kind(out, kind)?;
+        } else if let Some(e) = LinuxError::from_name(arg) {
+            print_linux(out, e)?;
+        } else {
+            return Ok(false);
+        }
+        return Ok(true);
+    };
+
+    let mut found = false;
+    // An `AxError` code is either a positive `AxErrorKind` code or a negated
+    // errno, while a bare errno is positive.
+    if let Some(e) = LinuxError::from_code(code.unsigned_abs() as i32) {
+        print_linux(out, e)?;
+        found = true;
+    }
+    if let Some(kind) = AxErrorKind::from_code(code) {
+        print_kind(out, kind)?;
+        found = true;
+    }
+    Ok(found)
+}
+
+fn list(out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "AxErrorKind:")?;
+    for kind in (1..).map_while(AxErrorKind::from_code) {
+        let e = kind.to_linux();
+        writeln!(
+            out,
+            "{:>5}  {:<24} {:<24} {}",
+            kind.code(),
+            kind.name(),
+            e.name(),
+            kind.message()
+        )?;
+    }
+    writeln!(out, "\nLinuxError:")?;
+    for e in (1..=4095).filter_map(LinuxError::from_code) {
+        let kind = AxErrorKind::try_from(e).map_or("-", AxErrorKind::name);
+        writeln!(
+            out,
+            "{:>5}  {:<24} {:<24} {}",
+            e.code(),
+            e.name(),
+            kind,
+            e.message()
+        )?;
+    }
+    Ok(())
+}
+
+/// Appends the name of the error after each negative error number in `line`,
+/// e.g. `-22` becomes `-22 (EINVAL)`.
+fn annotate(line: &str) -> String {
+    let bytes = line.as_bytes();
+    let mut result = String::with_capacity(line.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts_number = bytes[i] == b'-'
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric())
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !starts_number {
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        }
+        let ends_number = end == bytes.len() || !bytes[end].is_ascii_alphanumeric();
+        let err = line[i + 1..end]
+            .parse()
+            .ok()
+            .and_then(LinuxError::from_code);
+        if let (true, Some(e)) = (ends_number, err) {
+            result.push_str(&line[copied..end]);
+            result.push_str(" (");
+            result.push_str(e.name());
+            result.push(')');
+            copied = end;
+        }
+        i = end;
+    }
+    result.push_str(&line[copied..]);
+    result
+}
+
+fn filter() -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        writeln!(out, "{}", annotate(&line?))?;
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) -> io::Result<ExitCode> {
+    let mut out = io::stdout().lock();
+    let mut lookups = Vec::new();
+    let mut options = true;
+    for arg in args {
+        match arg.as_str() {
+            "--" if options => options = false,
+            "-h" | "--help" if options => {
+                write!(out, "{USAGE}")?;
+                return Ok(ExitCode::SUCCESS);
+            }
+            "-l" | "--list" if options => {
+                list(&mut out)?;
+                return Ok(ExitCode::SUCCESS);
+            }
+            "-f" | "--filter" if options => {
+                filter()?;
+                return Ok(ExitCode::SUCCESS);
+            }
+            _ => lookups.push(arg),
+        }
+    }
+    if lookups.is_empty() {
+        eprint!("{USAGE}");
+        return Ok(ExitCode::from(2));
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    for (i, arg) in lookups.into_iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        if !lookup(&mut out, arg)? {
+            eprintln!("axerrno: unknown error code or name: {arg}");
+            status = ExitCode::FAILURE;
+        }
+    }
+    Ok(status)
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args) {
+        Ok(status) => status,
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("axerrno: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotate() {
+        assert_eq!(
+            annotate("open failed: -2, ret=-110"),
+            "open failed: -2 (ENOENT), ret=-110 (ETIMEDOUT)"
+        );
+        assert_eq!(annotate("-22"), "-22 (EINVAL)");
+        assert_eq!(
+            annotate("x-22 -22x -9999 -41 1-2"),
+            "x-22 -22x -9999 -41 1-2"
+        );
+    }
+
+    #[test]
+    fn test_lookup() {
+        let mut out = Vec::new();
+        assert!(lookup(&mut out, "2").unwrap());
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("LinuxError::ENOENT = 2"));
+        assert!(out.contains("AxError code: -2\n"));
+        assert!(out.contains("AxErrorKind::AlreadyConnected = 2"));
+
+        let mut out = Vec::new();
+        assert!(lookup(&mut out, "-110").unwrap());
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("LinuxError::ETIMEDOUT = 110"));
+        assert!(out.contains("-> AxErrorKind::TimedOut"));
+
+        let mut out = Vec::new();
+        assert!(lookup(&mut out, "NotFound").unwrap());
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("AxError code: 30\n"));
+        assert!(out.contains("-> LinuxError::ENOENT = 2"));
+
+        assert!(!lookup(&mut Vec::new(), "ENOPE").unwrap());
+        assert!(!lookup(&mut Vec::new(), "0").unwrap());
+    }
+}
@@ -0,0 +1,194 @@
+//! Conversions between results and C ABI return conventions.
+//!
+//! | Convention | Success      | Failure                            |
+//! |------------|--------------|------------------------------------|
+//! | syscall    | value `>= 0` | `-errno`, in `-MAX_ERRNO..=-1`     |
+//! | pthread    | `0`          | `errno`                            |
+//! | libc       | value `>= 0` | `-1`, with `errno` set             |
+//! | io_uring   | value `>= 0` | `-errno` in the CQE `res` field    |
+//!
+//! Each `to_*` function has a `from_*` counterpart decoding in the opposite
+//! direction. Error codes that are not valid [`LinuxError`]s decode to
+//! [`LinuxError::EIO`].
+//!
+//! # Examples
+//!
+//! ```
+//! use axerrno::abi::*;
+//! use axerrno::{AxError, AxResult, LinuxError};
+//!
+//! assert_eq!(from_syscall_ret(-2), Err(LinuxError::ENOENT));
+//! assert_eq!(from_syscall_ret(-4096), Ok(-4096));
+//!
+//! let res: AxResult<isize> = Err(AxError::WouldBlock);
+//! assert_eq!(to_syscall_ret(res), -11);
+//! assert_eq!(to_pthread_ret(Err::<(), _>(LinuxError::EBUSY)), 16);
+//! ```
+
+use crate::{errno, LinuxError, LinuxResult};
+
+/// The maximum error number returned by system calls.
+pub const MAX_ERRNO: i32 = 4095;
+
+fn decode(code: i32) -> LinuxError {
+    LinuxError::try_from(code).unwrap_or(LinuxError::EIO)
+}
+
+/// Converts a result into a Linux system call return value.
+///
+/// Restart requests (e.g. [`LinuxError::ERESTARTSYS`]) are kept as is, since
+/// signal delivery has to see them to decide with
+/// [`LinuxError::syscall_restart`] whether to restart the system call. Other
+/// kernel-internal errors are converted with [`LinuxError::to_user`].
+pub fn to_syscall_ret<E: Into<LinuxError>>(res: Result<isize, E>) -> isize {
+    match res {
+        Ok(value) => value,
+        Err(e) => {
+            let e = e.into();
+            let e = if e.is_restart() { e } else { e.to_user() };
+            -(e.code() as isize)
+        }
+    }
+}
+
+/// Decodes a Linux system call return value.
+///
+/// Values in `-MAX_ERRNO..=-1` are errors, everything else is a success
+/// (e.g. a pointer returned by `mmap`).
+///
+/// Error numbers that are not valid [`LinuxError`]s are lost and decode to
+/// [`LinuxError::EIO`].
+pub fn from_syscall_ret(ret: isize) -> LinuxResult<isize> {
+    if (-(MAX_ERRNO as isize)..0).contains(&ret) {
+        Err(decode(-ret as i32))
+    } else {
+        Ok(ret)
+    }
+}
+
+/// Converts a result into a pthread-style return value: `0` on success, or
+/// the positive error number on failure.
+///
+/// Kernel-internal errors are converted with [`LinuxError::to_user`].
+pub fn to_pthread_ret<E: Into<LinuxError>>(res: Result<(), E>) -> i32 {
+    match res {
+        Ok(()) => 0,
+        Err(e) => e.into().to_user().code(),
+    }
+}
+
+/// Decodes a pthread-style return value.
+///
+/// Error numbers that are not valid [`LinuxError`]s are lost and decode to
+/// [`LinuxError::EIO`].
+pub fn from_pthread_ret(ret: i32) -> LinuxResult {
+    match ret {
+        0 => Ok(()),
+        _ => Err(decode(ret)),
+    }
+}
+
+/// Converts a result into a libc-style return value: the value on success, or
+/// `-1` on failure, with the error stored in [`errno`].
+///
+/// Kernel-internal errors are converted with [`LinuxError::to_user`].
+pub fn to_libc_ret<E: Into<LinuxError>>(res: Result<i32, E>) -> i32 {
+    match res {
+        Ok(value) => value,
+        Err(e) => {
+            errno::set_errno(e);
+            -1
+        }
+    }
+}
+
+/// Decodes a libc-style return value, reading [`errno`] on
+/// failure.
+///
+/// `errno` values that are not valid [`LinuxError`]s are lost and decode to
+/// [`LinuxError::EIO`].
+pub fn from_libc_ret(ret: i32) -> LinuxResult<i32> {
+    match ret {
+        -1 => Err(decode(errno::raw_errno())),
+        _ => Ok(ret),
+    }
+}
+
+/// Converts a result into the `res` field of an io_uring completion queue
+/// entry.
+///
+/// Kernel-internal errors are converted with [`LinuxError::to_user`].
+pub fn to_cqe_res<E: Into<LinuxError>>(res: Result<i32, E>) -> i32 {
+    match res {
+        Ok(value) => value,
+        Err(e) => -e.into().to_user().code(),
+    }
+}
+
+/// Decodes the `res` field of an io_uring completion queue entry.
+///
+/// Error numbers that are not valid [`LinuxError`]s are lost and decode to
+/// [`LinuxError::EIO`].
+pub fn from_cqe_res(res: i32) -> LinuxResult<i32> {
+    if res < 0 {
+        Err(decode(-res))
+    } else {
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxError;
+
+    /// Serializes the tests that go through the global `errno`.
+    static ERRNO_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn test_round_trip() {
+        let _guard = ERRNO_LOCK.lock().unwrap();
+        for err in [LinuxError::EPERM, LinuxError::EAGAIN, LinuxError::EHWPOISON] {
+            assert_eq!(from_syscall_ret(to_syscall_ret(Err(err))), Err(err));
+            assert_eq!(from_pthread_ret(to_pthread_ret(Err(err))), Err(err));
+            assert_eq!(from_libc_ret(to_libc_ret(Err(err))), Err(err));
+            assert_eq!(from_cqe_res(to_cqe_res(Err(err))), Err(err));
+        }
+        assert_eq!(from_syscall_ret(to_syscall_ret::<AxError>(Ok(42))), Ok(42));
+        assert_eq!(
+            from_syscall_ret(-(MAX_ERRNO as isize)),
+            Err(LinuxError::EIO)
+        );
+        assert_eq!(from_pthread_ret(to_pthread_ret::<AxError>(Ok(()))), Ok(()));
+        assert_eq!(from_libc_ret(to_libc_ret::<AxError>(Ok(0))), Ok(0));
+        assert_eq!(from_cqe_res(to_cqe_res::<AxError>(Ok(7))), Ok(7));
+    }
+
+    #[test]
+    fn test_syscall_ret_kernel_internal() {
+        use LinuxError::*;
+        assert_eq!(to_syscall_ret(Err(ERESTARTSYS)), -512);
+        assert_eq!(to_syscall_ret(Err(ERESTART_RESTARTBLOCK)), -516);
+        assert_eq!(to_syscall_ret(Err(ENOIOCTLCMD)), -(ENOTTY as isize));
+        assert_eq!(to_syscall_ret(Err(EPROBE_DEFER)), -(EAGAIN as isize));
+        assert_eq!(to_syscall_ret(Err(ENOTSUPP)), -(EOPNOTSUPP as isize));
+    }
+
+    #[test]
+    fn test_ret_kernel_internal() {
+        use LinuxError::*;
+        let _guard = ERRNO_LOCK.lock().unwrap();
+        for (err, user) in [
+            (ERESTARTSYS, EINTR),
+            (ERESTART_RESTARTBLOCK, EINTR),
+            (ENOIOCTLCMD, ENOTTY),
+            (EPROBE_DEFER, EAGAIN),
+            (ENOTSUPP, EOPNOTSUPP),
+        ] {
+            assert_eq!(to_pthread_ret(Err(err)), user as i32);
+            assert_eq!(to_libc_ret(Err(err)), -1);
+            assert_eq!(errno::errno(), Some(user));
+            assert_eq!(to_cqe_res(Err(err)), -(user as i32));
+        }
+    }
+}
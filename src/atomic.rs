@@ -0,0 +1,118 @@
+//! Atomic storage of pending errors.
+
+use core::fmt;
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use crate::AxError;
+
+/// An `Option<AxError>` which can be safely shared between threads and
+/// interrupt contexts.
+///
+/// This is useful to park an error until someone picks it up, e.g. a socket
+/// `SO_ERROR`, the completion status of an asynchronous I/O request, or an
+/// error raised by an interrupt handler on behalf of a task.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AtomicAxError, AxError};
+/// # use core::sync::atomic::Ordering;
+/// static SO_ERROR: AtomicAxError = AtomicAxError::new(None);
+///
+/// assert_eq!(SO_ERROR.set_if_empty(AxError::ConnectionReset, Ordering::AcqRel), Ok(()));
+/// assert_eq!(
+///     SO_ERROR.set_if_empty(AxError::BrokenPipe, Ordering::AcqRel),
+///     Err(AxError::ConnectionReset),
+/// );
+/// assert_eq!(SO_ERROR.take(Ordering::AcqRel), Some(AxError::ConnectionReset));
+/// assert_eq!(SO_ERROR.load(Ordering::Acquire), None);
+/// ```
+#[repr(transparent)]
+pub struct AtomicAxError(AtomicI32);
+
+const fn encode(err: Option<AxError>) -> i32 {
+    match err {
+        Some(err) => err.code(),
+        None => 0,
+    }
+}
+
+const fn decode(code: i32) -> Option<AxError> {
+    // Only valid error codes, which are never 0, are ever stored.
+    AxError::from_code(code)
+}
+
+/// Returns the strongest failure ordering allowed for `order`.
+const fn failure_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::Acquire | Ordering::AcqRel => Ordering::Acquire,
+        _ => Ordering::SeqCst,
+    }
+}
+
+impl AtomicAxError {
+    /// Creates a new atomic error.
+    pub const fn new(err: Option<AxError>) -> Self {
+        Self(AtomicI32::new(encode(err)))
+    }
+
+    /// Loads the error.
+    pub fn load(&self, order: Ordering) -> Option<AxError> {
+        decode(self.0.load(order))
+    }
+
+    /// Stores an error, overwriting the previous one.
+    pub fn store(&self, err: Option<AxError>, order: Ordering) {
+        self.0.store(encode(err), order);
+    }
+
+    /// Stores an error, returning the previous one.
+    pub fn swap(&self, err: Option<AxError>, order: Ordering) -> Option<AxError> {
+        decode(self.0.swap(encode(err), order))
+    }
+
+    /// Takes the error out, leaving `None` in its place.
+    pub fn take(&self, order: Ordering) -> Option<AxError> {
+        self.swap(None, order)
+    }
+
+    /// Stores `err` only if no error is pending, so that the first error wins.
+    ///
+    /// Returns the pending error if there is one.
+    pub fn set_if_empty(&self, err: AxError, order: Ordering) -> Result<(), AxError> {
+        self.0
+            .compare_exchange(0, err.code(), order, failure_ordering(order))
+            .map(|_| ())
+            .map_err(|code| decode(code).unwrap_or(err))
+    }
+
+    /// Consumes the atomic and returns the contained error.
+    pub fn into_inner(self) -> Option<AxError> {
+        decode(self.0.into_inner())
+    }
+}
+
+impl Default for AtomicAxError {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl From<Option<AxError>> for AtomicAxError {
+    fn from(err: Option<AxError>) -> Self {
+        Self::new(err)
+    }
+}
+
+impl From<AxError> for AtomicAxError {
+    fn from(err: AxError) -> Self {
+        Self::new(Some(err))
+    }
+}
+
+impl fmt::Debug for AtomicAxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
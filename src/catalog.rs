@@ -0,0 +1,118 @@
+//! Localized error message catalogs.
+//!
+//! `Display` for [`AxErrorKind`], [`AxError`] and [`LinuxError`] looks up
+//! the error description in the installed [`MessageCatalog`]. Until a catalog
+//! is installed with [`set_message_catalog`], the built-in [`English`]
+//! descriptions are used.
+//!
+//! # Examples
+//!
+//! ```
+//! use axerrno::{AxError, SimplifiedChinese};
+//!
+//! axerrno::set_message_catalog(&SimplifiedChinese).unwrap();
+//! assert_eq!(AxError::NotFound.to_string(), "实体不存在");
+//! assert_eq!(format!("{:#}", AxError::NotFound), "NotFound: 实体不存在");
+//! ```
+
+mod zh_cn;
+
+pub use zh_cn::SimplifiedChinese;
+
+use crate::global::GlobalRef;
+use crate::{AxError, AxErrorData, AxErrorKind, AxResult, LinuxError};
+
+/// A table of error descriptions in some language.
+///
+/// Both methods may return `None` for errors without a translation, in which
+/// case the [`English`] description is used.
+pub trait MessageCatalog: Sync {
+    /// Returns the description of an [`AxErrorKind`].
+    fn kind_message(&self, kind: AxErrorKind) -> Option<&'static str>;
+
+    /// Returns the description of a [`LinuxError`].
+    fn linux_message(&self, err: LinuxError) -> Option<&'static str>;
+}
+
+/// The built-in English descriptions, as returned by [`AxErrorKind::as_str`]
+/// and [`LinuxError::as_str`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct English;
+
+impl MessageCatalog for English {
+    fn kind_message(&self, kind: AxErrorKind) -> Option<&'static str> {
+        Some(kind.as_str())
+    }
+
+    fn linux_message(&self, err: LinuxError) -> Option<&'static str> {
+        Some(err.as_str())
+    }
+}
+
+static CATALOG: GlobalRef<dyn MessageCatalog> = GlobalRef::new();
+
+/// Installs the global message catalog.
+///
+/// Returns [`AxError::AlreadyExists`] if a catalog has already been
+/// installed.
+pub fn set_message_catalog(catalog: &'static dyn MessageCatalog) -> AxResult {
+    CATALOG.set(catalog)
+}
+
+/// Returns the installed message catalog, or [`English`] if none is
+/// installed.
+pub fn message_catalog() -> &'static dyn MessageCatalog {
+    CATALOG.get().unwrap_or(&English)
+}
+
+impl AxErrorKind {
+    /// Returns the error description from the installed [`MessageCatalog`].
+    pub fn message(self) -> &'static str {
+        message_catalog()
+            .kind_message(self)
+            .unwrap_or_else(|| self.as_str())
+    }
+}
+
+impl LinuxError {
+    /// Returns the error description from the installed [`MessageCatalog`].
+    pub fn message(self) -> &'static str {
+        message_catalog()
+            .linux_message(self)
+            .unwrap_or_else(|| self.as_str())
+    }
+}
+
+impl AxError {
+    /// Returns the error description from the installed [`MessageCatalog`].
+    pub fn message(self) -> &'static str {
+        match self.data() {
+            AxErrorData::Ax(kind) => kind.message(),
+            AxErrorData::Linux(e) => e.message(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::EnumCount;
+
+    use super::*;
+
+    #[test]
+    fn test_simplified_chinese() {
+        for code in 1..=AxErrorKind::COUNT as i32 {
+            let kind = AxErrorKind::try_from(code).unwrap();
+            assert!(SimplifiedChinese.kind_message(kind).is_some(), "{kind:?}");
+        }
+        for code in 1..=524 {
+            if let Ok(err) = LinuxError::try_from(code) {
+                assert!(SimplifiedChinese.linux_message(err).is_some(), "{err:?}");
+            }
+        }
+        assert_eq!(
+            SimplifiedChinese.linux_message(LinuxError::ENOENT),
+            Some("没有那个文件或目录")
+        );
+    }
+}
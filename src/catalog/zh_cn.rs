@@ -0,0 +1,204 @@
+//! Simplified Chinese error descriptions.
+
+use super::MessageCatalog;
+use crate::{AxErrorKind, LinuxError};
+
+/// The Simplified Chinese (`zh_CN`) message catalog.
+///
+/// The descriptions of [`LinuxError`]s follow the glibc `zh_CN` translation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SimplifiedChinese;
+
+impl MessageCatalog for SimplifiedChinese {
+    fn kind_message(&self, kind: AxErrorKind) -> Option<&'static str> {
+        use AxErrorKind::*;
+        Some(match kind {
+            AddrInUse => "地址已在使用",
+            AlreadyConnected => "已连接",
+            AlreadyExists => "实体已存在",
+            ArgumentListTooLong => "参数列表过长",
+            BadAddress => "错误的地址",
+            BadFileDescriptor => "错误的文件描述符",
+            BadState => "错误的内部状态",
+            BrokenPipe => "断开的管道",
+            ConnectionRefused => "连接被拒绝",
+            ConnectionReset => "连接被重置",
+            CrossesDevices => "跨设备链接或重命名",
+            DirectoryNotEmpty => "目录非空",
+            FilesystemLoop => "文件系统循环或间接层数过多",
+            IllegalBytes => "非法的字节序列",
+            InProgress => "操作正在进行",
+            Interrupted => "操作被中断",
+            InvalidData => "无效的数据",
+            InvalidExecutable => "无效的可执行文件格式",
+            InvalidInput => "无效的输入参数",
+            Io => "输入/输出错误",
+            IsADirectory => "是一个目录",
+            NameTooLong => "文件名过长",
+            NoMemory => "内存不足",
+            NoSuchDevice => "没有那个设备",
+            NoSuchProcess => "没有那个进程",
+            NotADirectory => "不是目录",
+            NotASocket => "不是套接字",
+            NotATty => "对设备不适当的 ioctl 操作",
+            NotConnected => "未连接",
+            NotFound => "实体不存在",
+            OperationNotPermitted => "不允许的操作",
+            OperationNotSupported => "不支持的操作",
+            OutOfRange => "结果超出范围",
+            PermissionDenied => "权限不够",
+            ReadOnlyFilesystem => "只读文件系统",
+            ResourceBusy => "资源忙",
+            StorageFull => "存储空间不足",
+            TimedOut => "超时",
+            TooManyOpenFiles => "打开的文件过多",
+            UnexpectedEof => "意外的文件结尾",
+            Unsupported => "不支持的操作",
+            WouldBlock => "操作将会阻塞",
+            WriteZero => "写入零字节",
+        })
+    }
+
+    fn linux_message(&self, err: LinuxError) -> Option<&'static str> {
+        use LinuxError::*;
+        Some(match err {
+            EPERM => "不允许的操作",
+            ENOENT => "没有那个文件或目录",
+            ESRCH => "没有那个进程",
+            EINTR => "被中断的系统调用",
+            EIO => "输入/输出错误",
+            ENXIO => "没有那个设备或地址",
+            E2BIG => "参数列表过长",
+            ENOEXEC => "可执行文件格式错误",
+            EBADF => "错误的文件描述符",
+            ECHILD => "没有子进程",
+            EAGAIN => "资源暂时不可用",
+            ENOMEM => "无法分配内存",
+            EACCES => "权限不够",
+            EFAULT => "错误的地址",
+            ENOTBLK => "需要块设备",
+            EBUSY => "设备或资源忙",
+            EEXIST => "文件已存在",
+            EXDEV => "无效的跨设备链接",
+            ENODEV => "没有那个设备",
+            ENOTDIR => "不是目录",
+            EISDIR => "是一个目录",
+            EINVAL => "无效的参数",
+            ENFILE => "系统中打开的文件过多",
+            EMFILE => "打开的文件过多",
+            ENOTTY => "对设备不适当的 ioctl 操作",
+            ETXTBSY => "文本文件忙",
+            EFBIG => "文件过大",
+            ENOSPC => "设备上没有空间",
+            ESPIPE => "非法 seek 操作",
+            EROFS => "只读文件系统",
+            EMLINK => "过多的链接",
+            EPIPE => "断开的管道",
+            EDOM => "数值参数超出域",
+            ERANGE => "数值结果超出范围",
+            EDEADLK => "已避免资源死锁",
+            ENAMETOOLONG => "文件名过长",
+            ENOLCK => "没有可用的锁",
+            ENOSYS => "函数未实现",
+            ENOTEMPTY => "目录非空",
+            ELOOP => "符号连接的层数过多",
+            ENOMSG => "没有符合需求格式的消息",
+            EIDRM => "标识符已删除",
+            ECHRNG => "通道编号超出范围",
+            EL2NSYNC => "级别 2 尚未同步",
+            EL3HLT => "级别 3 已停止",
+            EL3RST => "级别 3 已重置",
+            ELNRNG => "链接数超出范围",
+            EUNATCH => "未加载协议驱动程序",
+            ENOCSI => "没有可用的 CSI 结构",
+            EL2HLT => "级别 2 已停止",
+            EBADE => "无效的交换",
+            EBADR => "无效的请求描述符",
+            EXFULL => "交换满",
+            ENOANO => "没有 anode",
+            EBADRQC => "无效的请求码",
+            EBADSLT => "无效的槽",
+            EBFONT => "错误的字体文件格式",
+            ENOSTR => "设备不是流",
+            ENODATA => "没有可用的数据",
+            ETIME => "计时器超时",
+            ENOSR => "流资源不足",
+            ENONET => "机器不在网络中",
+            ENOPKG => "包未安装",
+            EREMOTE => "对象是远程的",
+            ENOLINK => "链接已有服务",
+            EADV => "通告错误",
+            ESRMNT => "Srmount 错误",
+            ECOMM => "发送时出现通讯错误",
+            EPROTO => "协议错误",
+            EMULTIHOP => "尝试 Multihop",
+            EDOTDOT => "RFS 特定错误",
+            EBADMSG => "错误的消息",
+            EOVERFLOW => "对已定义的数据类型来说值过大",
+            ENOTUNIQ => "名称在网络上不是唯一的",
+            EBADFD => "文件描述符处于错误状态",
+            EREMCHG => "远程地址已改变",
+            ELIBACC => "无法访问必需的共享库",
+            ELIBBAD => "正在访问一个已损坏的共享库",
+            ELIBSCN => "a.out 中的 .lib 节已损坏",
+            ELIBMAX => "试图与过多的共享库相链接",
+            ELIBEXEC => "无法直接执行共享库",
+            EILSEQ => "无效或不完整的多字节字符或宽字符",
+            ERESTART => "被中断的系统调用应该重新启动",
+            ESTRPIPE => "流管道错误",
+            EUSERS => "用户过多",
+            ENOTSOCK => "对非套接字进行套接字操作",
+            EDESTADDRREQ => "需要目标地址",
+            EMSGSIZE => "消息过长",
+            EPROTOTYPE => "错误的 socket 协议类型",
+            ENOPROTOOPT => "不可用的协议",
+            EPROTONOSUPPORT => "不支持的协议",
+            ESOCKTNOSUPPORT => "不支持的套接字类型",
+            EOPNOTSUPP => "不支持的操作",
+            EPFNOSUPPORT => "不支持的协议族",
+            EAFNOSUPPORT => "协议不支持的地址族",
+            EADDRINUSE => "地址已在使用",
+            EADDRNOTAVAIL => "无法指定被请求的地址",
+            ENETDOWN => "网络已关闭",
+            ENETUNREACH => "网络不可达",
+            ENETRESET => "重置时断开网络连接",
+            ECONNABORTED => "软件导致的连接中止",
+            ECONNRESET => "连接被对方重置",
+            ENOBUFS => "没有可用的缓冲区空间",
+            EISCONN => "传输端点已连接",
+            ENOTCONN => "传输端点尚未连接",
+            ESHUTDOWN => "无法在传输端点关闭以后发送",
+            ETOOMANYREFS => "过多的引用：无法接合",
+            ETIMEDOUT => "连接超时",
+            ECONNREFUSED => "拒绝连接",
+            EHOSTDOWN => "主机关闭",
+            EHOSTUNREACH => "没有到主机的路由",
+            EALREADY => "操作已经在进行",
+            EINPROGRESS => "操作现在正在进行",
+            ESTALE => "过旧的文件控柄",
+            EUCLEAN => "结构需要清理",
+            ENOTNAM => "不是 XENIX 命名的类型文件",
+            ENAVAIL => "没有可用的 XENIX 信号量",
+            EISNAM => "是一个有名类型文件",
+            EREMOTEIO => "远程 I/O 错误",
+            EDQUOT => "超出磁盘限额",
+            ENOMEDIUM => "找不到介质",
+            EMEDIUMTYPE => "错误的介质类型",
+            ECANCELED => "操作已取消",
+            ENOKEY => "需要的关键字不存在",
+            EKEYEXPIRED => "关键字已过期",
+            EKEYREVOKED => "键值已取消",
+            EKEYREJECTED => "键值被服务所拒绝",
+            EOWNERDEAD => "拥有者已死亡",
+            ENOTRECOVERABLE => "状态无法恢复",
+            ERFKILL => "由于 RF-kill 而无法操作",
+            EHWPOISON => "内存页有硬件错误",
+            ERESTARTSYS | ERESTARTNOINTR | ERESTARTNOHAND | ERESTART_RESTARTBLOCK => {
+                "系统调用需要重新启动"
+            }
+            ENOIOCTLCMD => "没有那个 ioctl 命令",
+            EPROBE_DEFER => "驱动探测需要推迟",
+            ENOTSUPP => "不支持的操作",
+        })
+    }
+}
@@ -0,0 +1,242 @@
+//! Scoped error context.
+//!
+//! A context frame is a short description of what the current task is doing,
+//! such as `"mounting /dev/sda1"`. Frames are pushed with [`context`] and
+//! popped when the returned [`ContextGuard`] is dropped. Errors created by
+//! [`ax_err!`] or [`ax_err_type!`] print the active frames along with the
+//! warning message.
+//!
+//! Where the frames live is up to the OS: install a [`ContextStorage`] with
+//! [`set_context_storage`] that forwards to a per-task or per-CPU
+//! [`ContextStack`]. Without storage installed, context frames are discarded.
+//!
+//! [`ax_err!`]: crate::ax_err
+//! [`ax_err_type!`]: crate::ax_err_type
+
+use core::cell::Cell;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::global::GlobalRef;
+use crate::AxResult;
+
+/// The storage backend of context frames.
+///
+/// Implementations are expected to forward to a stack owned by the current
+/// task (or CPU), e.g. a [`ContextStack`] in the task control block.
+pub trait ContextStorage: Sync {
+    /// Pushes a frame onto the context stack of the current task.
+    fn push(&self, frame: &'static str);
+
+    /// Pops the innermost frame from the context stack of the current task.
+    fn pop(&self);
+
+    /// Calls `f` on each frame of the current task, from the outermost to the
+    /// innermost.
+    fn for_each(&self, f: &mut dyn FnMut(&'static str));
+}
+
+static STORAGE: GlobalRef<dyn ContextStorage> = GlobalRef::new();
+
+/// Installs the global context storage.
+///
+/// Returns [`AxError::AlreadyExists`] if a storage has already been installed.
+///
+/// [`AxError::AlreadyExists`]: crate::AxError::AlreadyExists
+pub fn set_context_storage(storage: &'static dyn ContextStorage) -> AxResult {
+    STORAGE.set(storage)
+}
+
+/// Pushes a context frame, which stays active until the returned guard is
+/// dropped.
+///
+/// # Examples
+///
+/// ```
+/// fn mount(dev: &str) -> axerrno::AxResult {
+///     let _guard = axerrno::context("mounting root filesystem");
+///     // Also print "| context: mounting root filesystem" if a context storage
+///     // is installed.
+///     axerrno::ax_err!(NotFound, dev)
+/// }
+/// # assert!(mount("/dev/sda1").is_err());
+/// ```
+pub fn context(frame: &'static str) -> ContextGuard {
+    let storage = STORAGE.get();
+    if let Some(storage) = storage {
+        storage.push(frame);
+    }
+    ContextGuard {
+        storage,
+        _not_send: PhantomData,
+    }
+}
+
+/// Returns the context frames active in the current task.
+pub fn current_context() -> ContextChain {
+    ContextChain(PhantomData)
+}
+
+/// A RAII guard that pops its context frame when dropped.
+///
+/// Created by [`context`].
+#[must_use = "the context frame is popped as soon as the guard is dropped"]
+pub struct ContextGuard {
+    storage: Option<&'static dyn ContextStorage>,
+    // The frame must be popped on the task that pushed it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        if let Some(storage) = self.storage {
+            storage.pop();
+        }
+    }
+}
+
+/// The context frames active in the current task.
+///
+/// Displays as `" | context: outer > inner"`, or as nothing if no frame is
+/// active. Created by [`current_context`].
+pub struct ContextChain(PhantomData<*const ()>);
+
+impl ContextChain {
+    /// Calls `f` on each active frame, from the outermost to the innermost.
+    pub fn for_each(&self, mut f: impl FnMut(&'static str)) {
+        if let Some(storage) = STORAGE.get() {
+            storage.for_each(&mut f);
+        }
+    }
+
+    /// Returns `true` if no frame is active.
+    pub fn is_empty(&self) -> bool {
+        let mut empty = true;
+        self.for_each(|_| empty = false);
+        empty
+    }
+}
+
+impl fmt::Display for ContextChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut res = Ok(());
+        let mut first = true;
+        self.for_each(|frame| {
+            if res.is_ok() {
+                let sep = if first { " | context: " } else { " > " };
+                res = write!(f, "{sep}{frame}");
+                first = false;
+            }
+        });
+        res
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ContextChain {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let mut sep = defmt::intern!(" | context: ");
+        self.for_each(|frame| {
+            defmt::write!(f, "{=istr}{=str}", sep, frame);
+            sep = defmt::intern!(" > ");
+        });
+    }
+}
+
+/// A fixed-capacity stack of context frames.
+///
+/// This is meant to be embedded in a task or per-CPU structure and accessed
+/// through a [`ContextStorage`] implementation. Frames pushed beyond the
+/// capacity are dropped, but still counted so that pushes and pops stay
+/// balanced.
+pub struct ContextStack<const N: usize> {
+    frames: [Cell<&'static str>; N],
+    len: Cell<usize>,
+}
+
+impl<const N: usize> ContextStack<N> {
+    /// Creates an empty stack.
+    pub const fn new() -> Self {
+        Self {
+            frames: [const { Cell::new("") }; N],
+            len: Cell::new(0),
+        }
+    }
+
+    /// Pushes a frame onto the stack.
+    pub fn push(&self, frame: &'static str) {
+        let len = self.len.get();
+        if let Some(slot) = self.frames.get(len) {
+            slot.set(frame);
+        }
+        self.len.set(len + 1);
+    }
+
+    /// Pops the innermost frame from the stack.
+    pub fn pop(&self) {
+        self.len.set(self.len.get().saturating_sub(1));
+    }
+
+    /// Calls `f` on each stored frame, from the outermost to the innermost.
+    pub fn for_each(&self, f: &mut dyn FnMut(&'static str)) {
+        let len = self.len.get().min(N);
+        for frame in &self.frames[..len] {
+            f(frame.get());
+        }
+    }
+}
+
+impl<const N: usize> Default for ContextStack<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        static STACK: ContextStack<2> = const { ContextStack::new() };
+    }
+
+    struct ThreadLocalStorage;
+
+    impl ContextStorage for ThreadLocalStorage {
+        fn push(&self, frame: &'static str) {
+            STACK.with(|s| s.push(frame));
+        }
+
+        fn pop(&self) {
+            STACK.with(|s| s.pop());
+        }
+
+        fn for_each(&self, f: &mut dyn FnMut(&'static str)) {
+            STACK.with(|s| s.for_each(f));
+        }
+    }
+
+    #[test]
+    fn test_context_chain() {
+        set_context_storage(&ThreadLocalStorage).unwrap();
+        assert!(set_context_storage(&ThreadLocalStorage).is_err());
+        assert!(current_context().is_empty());
+        {
+            let _outer = context("mounting /dev/sda1");
+            {
+                let _inner = context("reading superblock");
+                let _overflow = context("dropped");
+                assert_eq!(
+                    format!("{}", current_context()),
+                    " | context: mounting /dev/sda1 > reading superblock"
+                );
+            }
+            let _next = context("replaying journal");
+            assert_eq!(
+                format!("{}", current_context()),
+                " | context: mounting /dev/sda1 > replaying journal"
+            );
+        }
+        assert_eq!(format!("{}", current_context()), "");
+    }
+}
@@ -0,0 +1,155 @@
+//! Display adapters for alternative error formats.
+
+use core::fmt;
+
+use crate::{AxError, LinuxError};
+
+/// Writes `name`, followed by the description unless it is just the name
+/// (e.g. because descriptions are compiled out and no catalog is installed).
+pub(crate) fn fmt_name_desc(f: &mut fmt::Formatter<'_>, name: &str, desc: &str) -> fmt::Result {
+    if desc == name {
+        f.write_str(name)
+    } else {
+        write!(f, "{name}: {desc}")
+    }
+}
+
+/// Displays the symbolic name of an error, e.g. `ENOENT` or `NotFound`.
+///
+/// Created by [`AxError::symbolic`] and [`LinuxError::symbolic`].
+#[derive(Clone, Copy, Debug)]
+pub struct Symbolic(&'static str);
+
+/// Displays the negative errno of an error, e.g. `-2`.
+///
+/// Created by [`AxError::numeric`] and [`LinuxError::numeric`].
+#[derive(Clone, Copy, Debug)]
+pub struct Numeric(LinuxError);
+
+/// Displays an error like the Linux kernel's `%pe` format, e.g. `-ENOENT`.
+///
+/// Created by [`AxError::kernel`] and [`LinuxError::kernel`].
+#[derive(Clone, Copy, Debug)]
+pub struct Kernel(LinuxError);
+
+/// Displays a failed system call result like `strace`, e.g.
+/// `-1 ENOENT (No such file or directory)`.
+///
+/// The description comes from the installed [`MessageCatalog`], and is left
+/// out if it is just the name.
+///
+/// [`MessageCatalog`]: crate::MessageCatalog
+///
+/// Created by [`AxError::strace`] and [`LinuxError::strace`].
+#[derive(Clone, Copy, Debug)]
+pub struct Strace(LinuxError);
+
+impl fmt::Display for Symbolic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.0)
+    }
+}
+
+impl fmt::Display for Numeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-{}", self.0.code())
+    }
+}
+
+impl fmt::Display for Kernel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-{}", self.0.name())
+    }
+}
+
+impl fmt::Display for Strace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, desc) = (self.0.name(), self.0.message());
+        if desc == name {
+            write!(f, "-1 {name}")
+        } else {
+            write!(f, "-1 {name} ({desc})")
+        }
+    }
+}
+
+impl LinuxError {
+    /// Returns an adapter displaying the symbolic name, e.g. `ENOENT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::LinuxError;
+    /// let err = LinuxError::ENOENT;
+    /// assert_eq!(err.symbolic().to_string(), "ENOENT");
+    /// assert_eq!(err.numeric().to_string(), "-2");
+    /// assert_eq!(err.kernel().to_string(), "-ENOENT");
+    /// # #[cfg(not(feature = "no-descriptions"))]
+    /// assert_eq!(err.strace().to_string(), "-1 ENOENT (No such file or directory)");
+    /// # #[cfg(not(feature = "no-descriptions"))]
+    /// assert_eq!(format!("{err:#}"), "ENOENT: No such file or directory");
+    /// ```
+    pub const fn symbolic(self) -> Symbolic {
+        Symbolic(self.name())
+    }
+
+    /// Returns an adapter displaying the negative errno, e.g. `-2`.
+    pub const fn numeric(self) -> Numeric {
+        Numeric(self)
+    }
+
+    /// Returns an adapter displaying the error like the Linux kernel's `%pe`
+    /// format, e.g. `-ENOENT`.
+    pub const fn kernel(self) -> Kernel {
+        Kernel(self)
+    }
+
+    /// Returns an adapter displaying the error like `strace`, e.g.
+    /// `-1 ENOENT (No such file or directory)`.
+    pub const fn strace(self) -> Strace {
+        Strace(self)
+    }
+}
+
+impl AxError {
+    /// Returns an adapter displaying the symbolic name, e.g. `NotFound` for
+    /// an [`AxErrorKind`] or `ENOENT` for a [`LinuxError`].
+    ///
+    /// The other adapters display the corresponding [`LinuxError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxError, LinuxError};
+    /// let err = AxError::NotFound;
+    /// assert_eq!(err.symbolic().to_string(), "NotFound");
+    /// assert_eq!(AxError::from(LinuxError::ENOENT).symbolic().to_string(), "ENOENT");
+    /// assert_eq!(err.numeric().to_string(), "-2");
+    /// assert_eq!(err.kernel().to_string(), "-ENOENT");
+    /// # #[cfg(not(feature = "no-descriptions"))]
+    /// assert_eq!(format!("{err:#}"), "NotFound: Entity not found");
+    /// ```
+    ///
+    /// [`AxErrorKind`]: crate::AxErrorKind
+    pub const fn symbolic(self) -> Symbolic {
+        Symbolic(self.name())
+    }
+
+    /// Returns an adapter displaying the negative errno of the corresponding
+    /// [`LinuxError`], e.g. `-2`.
+    pub const fn numeric(self) -> Numeric {
+        Numeric(self.to_linux())
+    }
+
+    /// Returns an adapter displaying the corresponding [`LinuxError`] like the
+    /// Linux kernel's `%pe` format, e.g. `-ENOENT`.
+    pub const fn kernel(self) -> Kernel {
+        Kernel(self.to_linux())
+    }
+
+    /// Returns an adapter displaying the corresponding [`LinuxError`] like
+    /// `strace`, e.g. `-1 ENOENT (No such file or directory)`.
+    pub const fn strace(self) -> Strace {
+        Strace(self.to_linux())
+    }
+}
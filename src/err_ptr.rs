@@ -0,0 +1,194 @@
+//! Kernel-style encoding of errors in pointer values (`ERR_PTR`).
+//!
+//! As in Linux, the top [`MAX_ERRNO`] values of the address space are never
+//! valid pointers, so a pointer can carry either an object or a negative
+//! errno. This is what C drivers ported from Linux expect.
+//!
+//! # Examples
+//!
+//! ```
+//! use axerrno::err_ptr::{err_ptr, is_err, ptr_err, ptr_err_or_zero};
+//! use axerrno::{AxError, LinuxError};
+//!
+//! let ptr = err_ptr::<u8>(AxError::NoMemory);
+//! assert!(is_err(ptr));
+//! assert_eq!(ptr_err(ptr), Some(LinuxError::ENOMEM));
+//! assert_eq!(ptr_err_or_zero(ptr), -12);
+//!
+//! let mut value = 0u8;
+//! assert!(!is_err(&mut value));
+//! assert_eq!(ptr_err_or_zero(&mut value), 0);
+//! ```
+//!
+//! [`MAX_ERRNO`]: crate::abi::MAX_ERRNO
+
+use core::fmt;
+use core::ptr::{self, NonNull};
+
+use crate::abi::MAX_ERRNO;
+use crate::{AxError, AxResult, LinuxError, LinuxResult};
+
+/// Encodes an error into a pointer value.
+pub fn err_ptr<T>(err: impl Into<LinuxError>) -> *mut T {
+    ptr::without_provenance_mut(-(err.into().code() as isize) as usize)
+}
+
+/// Returns `true` if the pointer value encodes an error.
+pub fn is_err<T>(ptr: *const T) -> bool {
+    ptr.addr() >= -(MAX_ERRNO as isize) as usize
+}
+
+/// Returns `true` if the pointer value encodes an error or is null.
+pub fn is_err_or_null<T>(ptr: *const T) -> bool {
+    ptr.is_null() || is_err(ptr)
+}
+
+/// Decodes the error encoded in a pointer value, or returns `None` if it is
+/// not an error.
+///
+/// Unknown error numbers are decoded as [`LinuxError::EIO`].
+pub fn ptr_err<T>(ptr: *const T) -> Option<LinuxError> {
+    if is_err(ptr) {
+        Some(LinuxError::try_from(ptr_err_or_zero(ptr).wrapping_neg()).unwrap_or(LinuxError::EIO))
+    } else {
+        None
+    }
+}
+
+/// Returns the negative errno encoded in a pointer value, or zero if it is
+/// not an error.
+pub fn ptr_err_or_zero<T>(ptr: *const T) -> i32 {
+    if is_err(ptr) {
+        ptr.addr() as isize as i32
+    } else {
+        0
+    }
+}
+
+/// A pointer that holds either a valid object or an encoded error.
+///
+/// This has the same layout as `*mut T`, so it can be passed across the FFI
+/// boundary to code using Linux's `ERR_PTR` convention.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, AxResult, ErrPtr, LinuxError};
+/// # use core::ptr::NonNull;
+/// let res: AxResult<NonNull<u32>> = Err(AxError::NoSuchDevice);
+/// let ptr = ErrPtr::from(res);
+/// assert!(ptr.is_err());
+/// assert_eq!(ptr.into_linux_result(), Err(LinuxError::ENODEV));
+/// ```
+#[repr(transparent)]
+pub struct ErrPtr<T>(*mut T);
+
+impl<T> ErrPtr<T> {
+    /// Wraps a raw pointer value, which may encode an error.
+    pub const fn from_raw(ptr: *mut T) -> Self {
+        Self(ptr)
+    }
+
+    /// Wraps a valid pointer.
+    pub const fn from_ptr(ptr: NonNull<T>) -> Self {
+        Self(ptr.as_ptr())
+    }
+
+    /// Encodes an error.
+    pub fn from_err(err: impl Into<LinuxError>) -> Self {
+        Self(err_ptr(err))
+    }
+
+    /// Returns the raw pointer value.
+    pub const fn as_raw(&self) -> *mut T {
+        self.0
+    }
+
+    /// Returns `true` if this encodes an error.
+    pub fn is_err(&self) -> bool {
+        is_err(self.0)
+    }
+
+    /// Returns the encoded error, if any.
+    pub fn err(&self) -> Option<LinuxError> {
+        ptr_err(self.0)
+    }
+
+    /// Converts into a [`LinuxResult`].
+    ///
+    /// A null pointer is converted into [`LinuxError::EFAULT`].
+    pub fn into_linux_result(self) -> LinuxResult<NonNull<T>> {
+        match self.err() {
+            Some(err) => Err(err),
+            None => NonNull::new(self.0).ok_or(LinuxError::EFAULT),
+        }
+    }
+
+    /// Converts into an [`AxResult`].
+    ///
+    /// A null pointer is converted into [`LinuxError::EFAULT`].
+    pub fn into_result(self) -> AxResult<NonNull<T>> {
+        self.into_linux_result().map_err(AxError::from)
+    }
+}
+
+impl<T> Clone for ErrPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ErrPtr<T> {}
+
+impl<T> PartialEq for ErrPtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.0, other.0)
+    }
+}
+
+impl<T> Eq for ErrPtr<T> {}
+
+impl<T, E: Into<LinuxError>> From<Result<NonNull<T>, E>> for ErrPtr<T> {
+    fn from(res: Result<NonNull<T>, E>) -> Self {
+        match res {
+            Ok(ptr) => Self::from_ptr(ptr),
+            Err(err) => Self::from_err(err),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ErrPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.err() {
+            Some(err) => write!(f, "ErrPtr({err:?})"),
+            None => write!(f, "ErrPtr({:p})", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_err_ptr() {
+        assert!(!is_err(ptr::null::<u8>()));
+        assert!(is_err_or_null(ptr::null::<u8>()));
+        assert!(!is_err(ptr::without_provenance::<u8>(
+            -(MAX_ERRNO as isize + 1) as usize
+        )));
+        assert!(is_err(ptr::without_provenance::<u8>(usize::MAX)));
+
+        let mut value = 42u32;
+        let ptr = ErrPtr::from_ptr(NonNull::from(&mut value));
+        assert_eq!(ptr.err(), None);
+        assert_eq!(ptr.into_result(), Ok(NonNull::from(&mut value)));
+
+        let ptr = ErrPtr::<u32>::from_err(AxError::AlreadyExists);
+        assert_eq!(ptr.err(), Some(LinuxError::EEXIST));
+        assert_eq!(ptr.into_result(), Err(LinuxError::EEXIST.into()));
+
+        let ptr = ErrPtr::<u32>::from_raw(ptr::null_mut());
+        assert_eq!(ptr.into_linux_result(), Err(LinuxError::EFAULT));
+    }
+}
@@ -1,5 +1,5 @@
 /* SPDX-License-Identifier: GPL-2.0 WITH Linux-syscall-note */
-/* Copy from /usr/include/asm-generic/errno-base.h and /usr/include/asm-generic/errno.h */
+/* Copy from /usr/include/asm-generic/errno-base.h, /usr/include/asm-generic/errno.h and include/linux/errno.h */
 #ifndef _ASM_GENERIC_ERRNO_BASE_H
 #define _ASM_GENERIC_ERRNO_BASE_H
 
@@ -146,5 +146,17 @@
 #define ERFKILL         132 /* Operation not possible due to RF-kill */
 #define EHWPOISON       133 /* Memory page has hardware error */
 
+/*
+ * Kernel-internal error numbers from include/linux/errno.h.
+ * These should never be seen by user programs.
+ */
+#define ERESTARTSYS     512 /* Restart system call */
+#define ERESTARTNOINTR  513 /* Restart system call unconditionally */
+#define ERESTARTNOHAND  514 /* Restart system call if no handler */
+#define ENOIOCTLCMD     515 /* No ioctl command */
+#define ERESTART_RESTARTBLOCK 516 /* Restart by calling sys_restart_syscall */
+#define EPROBE_DEFER    517 /* Driver requests probe retry */
+#define ENOTSUPP        524 /* Operation is not supported */
+
 
 #endif
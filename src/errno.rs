@@ -0,0 +1,116 @@
+//! Per-task `errno` storage for the libc layer.
+//!
+//! The OS decides where `errno` lives by installing an [`ErrnoStorage`] with
+//! [`set_errno_storage`], typically forwarding to a field of the current task
+//! or to a thread-local variable. Until then, a single global `errno` is used.
+//!
+//! # Examples
+//!
+//! ```
+//! use axerrno::errno::{self, ErrnoGuard};
+//! use axerrno::LinuxError;
+//!
+//! errno::set_errno(LinuxError::ENOENT);
+//! {
+//!     // A signal handler must not clobber the errno of the interrupted code.
+//!     let _guard = ErrnoGuard::new();
+//!     errno::set_errno(LinuxError::EINTR);
+//! }
+//! assert_eq!(errno::errno(), Some(LinuxError::ENOENT));
+//! ```
+
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use crate::global::GlobalRef;
+use crate::{AxResult, LinuxError};
+
+/// The storage backend of `errno`.
+pub trait ErrnoStorage: Sync {
+    /// Returns the raw `errno` value of the current task.
+    fn get(&self) -> i32;
+
+    /// Sets the raw `errno` value of the current task.
+    fn set(&self, value: i32);
+}
+
+static STORAGE: GlobalRef<dyn ErrnoStorage> = GlobalRef::new();
+
+/// The `errno` used until a storage is installed.
+static FALLBACK: AtomicI32 = AtomicI32::new(0);
+
+/// Installs the global `errno` storage.
+///
+/// Returns [`AxError::AlreadyExists`] if a storage has already been installed.
+///
+/// [`AxError::AlreadyExists`]: crate::AxError::AlreadyExists
+pub fn set_errno_storage(storage: &'static dyn ErrnoStorage) -> AxResult {
+    STORAGE.set(storage)
+}
+
+/// Returns the raw `errno` value of the current task.
+pub fn raw_errno() -> i32 {
+    match STORAGE.get() {
+        Some(storage) => storage.get(),
+        None => FALLBACK.load(Ordering::Relaxed),
+    }
+}
+
+/// Sets the raw `errno` value of the current task.
+pub fn set_raw_errno(value: i32) {
+    match STORAGE.get() {
+        Some(storage) => storage.set(value),
+        None => FALLBACK.store(value, Ordering::Relaxed),
+    }
+}
+
+/// Returns the `errno` of the current task, or `None` if it is zero or not a
+/// known error code.
+pub fn errno() -> Option<LinuxError> {
+    LinuxError::try_from(raw_errno()).ok()
+}
+
+/// Sets the `errno` of the current task.
+///
+/// Kernel-internal errors are converted with [`LinuxError::to_user`], since
+/// `errno` is only ever read by user space.
+pub fn set_errno(err: impl Into<LinuxError>) {
+    set_raw_errno(err.into().to_user().code());
+}
+
+/// Resets the `errno` of the current task to zero.
+pub fn clear_errno() {
+    set_raw_errno(0);
+}
+
+/// A RAII guard that saves `errno` on creation and restores it when dropped.
+///
+/// Signal handlers should hold one, so that the `errno` of the interrupted
+/// code is preserved.
+#[must_use = "errno is restored as soon as the guard is dropped"]
+pub struct ErrnoGuard {
+    saved: i32,
+}
+
+impl ErrnoGuard {
+    /// Saves the current `errno`.
+    pub fn new() -> Self {
+        Self { saved: raw_errno() }
+    }
+
+    /// Returns the saved raw `errno` value.
+    pub fn saved(&self) -> i32 {
+        self.saved
+    }
+}
+
+impl Default for ErrnoGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ErrnoGuard {
+    fn drop(&mut self) {
+        set_raw_errno(self.saved);
+    }
+}
@@ -0,0 +1,317 @@
+//! Collections of errors accumulated by multi-step operations.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{AxError, AxErrorKind, AxResult};
+
+/// The policy for picking the representative error of a collection.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum PickPolicy {
+    /// The first recorded error.
+    #[default]
+    First,
+    /// The last recorded error.
+    Last,
+    /// The first of the most severe recorded errors.
+    ///
+    /// Data-loss and resource exhaustion errors (e.g. [`Io`], [`NoMemory`],
+    /// [`StorageFull`]) are the most severe, followed by other errors, then
+    /// benign ones ([`AlreadyExists`], [`NotFound`]), then transient ones
+    /// (e.g. [`WouldBlock`], [`Interrupted`], [`TimedOut`]).
+    ///
+    /// [`Io`]: AxErrorKind::Io
+    /// [`NoMemory`]: AxErrorKind::NoMemory
+    /// [`StorageFull`]: AxErrorKind::StorageFull
+    /// [`AlreadyExists`]: AxErrorKind::AlreadyExists
+    /// [`NotFound`]: AxErrorKind::NotFound
+    /// [`WouldBlock`]: AxErrorKind::WouldBlock
+    /// [`Interrupted`]: AxErrorKind::Interrupted
+    /// [`TimedOut`]: AxErrorKind::TimedOut
+    MostSevere,
+}
+
+fn severity(err: AxError) -> u8 {
+    use AxErrorKind::*;
+    match AxErrorKind::try_from(err) {
+        Ok(BadState | InvalidData | Io | NoMemory | ReadOnlyFilesystem | StorageFull) => 3,
+        Ok(UnexpectedEof | WriteZero) => 3,
+        Ok(AlreadyExists | NotFound) => 1,
+        Ok(InProgress | Interrupted | ResourceBusy | TimedOut | WouldBlock) => 0,
+        _ => 2,
+    }
+}
+
+/// Returns `true` if `new` should replace `worst` as the most severe error.
+fn more_severe(new: AxError, worst: Option<AxError>) -> bool {
+    worst.is_none_or(|worst| severity(new) > severity(worst))
+}
+
+fn fmt_errors(
+    f: &mut fmt::Formatter<'_>,
+    total: usize,
+    errors: impl Iterator<Item = AxError>,
+) -> fmt::Result {
+    if total == 0 {
+        return f.write_str("no errors");
+    }
+    write!(f, "{total} error{}:", if total == 1 { "" } else { "s" })?;
+    let mut shown = 0;
+    for (i, e) in errors.enumerate() {
+        let sep = if i == 0 { "" } else { ";" };
+        write!(f, "{sep} [{}] {e}", i + 1)?;
+        shown += 1;
+    }
+    if shown < total {
+        write!(f, "; ... and {} more", total - shown)?;
+    }
+    Ok(())
+}
+
+/// A fixed-capacity collection of errors.
+///
+/// Up to `N` errors are stored. Further errors are counted but not stored,
+/// except that the first, the last and the most severe errors are always
+/// kept, so that every [`PickPolicy`] is honored.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, AxErrors, AxResult, PickPolicy};
+/// fn unmount(path: &str) -> AxResult {
+///     match path {
+///         "/proc" => Err(AxError::ResourceBusy),
+///         "/mnt" => Err(AxError::Io),
+///         _ => Ok(()),
+///     }
+/// }
+///
+/// let mut errors = AxErrors::<4>::new();
+/// for path in ["/proc", "/tmp", "/mnt"] {
+///     errors.record(unmount(path));
+/// }
+/// assert_eq!(errors.len(), 2);
+/// # #[cfg(not(feature = "no-descriptions"))]
+/// assert_eq!(errors.to_string(), "2 errors: [1] Resource busy; [2] I/O error");
+/// assert_eq!(errors.into_result(PickPolicy::MostSevere), Err(AxError::Io));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct AxErrors<const N: usize> {
+    errors: [Option<AxError>; N],
+    len: usize,
+    first: Option<AxError>,
+    last: Option<AxError>,
+    worst: Option<AxError>,
+}
+
+impl<const N: usize> AxErrors<N> {
+    /// Creates an empty collection.
+    pub const fn new() -> Self {
+        Self {
+            errors: [None; N],
+            len: 0,
+            first: None,
+            last: None,
+            worst: None,
+        }
+    }
+
+    /// Adds an error to the collection.
+    pub fn push(&mut self, err: impl Into<AxError>) {
+        let err = err.into();
+        if let Some(slot) = self.errors.get_mut(self.len) {
+            *slot = Some(err);
+        }
+        self.len += 1;
+        self.first = self.first.or(Some(err));
+        self.last = Some(err);
+        if more_severe(err, self.worst) {
+            self.worst = Some(err);
+        }
+    }
+
+    /// Adds the error of `res` to the collection, if any, and returns its
+    /// value otherwise.
+    pub fn record<T, E: Into<AxError>>(&mut self, res: Result<T, E>) -> Option<T> {
+        res.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Returns the number of recorded errors, including those not stored.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no error has been recorded.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if more errors were recorded than could be stored.
+    pub const fn is_truncated(&self) -> bool {
+        self.len > N
+    }
+
+    /// Returns an iterator over the stored errors, in recording order.
+    pub fn iter(&self) -> impl Iterator<Item = AxError> + '_ {
+        self.errors.iter().map_while(|e| *e)
+    }
+
+    /// Returns the representative error according to `policy`, or `None` if
+    /// no error has been recorded.
+    pub fn pick(&self, policy: PickPolicy) -> Option<AxError> {
+        match policy {
+            PickPolicy::First => self.first,
+            PickPolicy::Last => self.last,
+            PickPolicy::MostSevere => self.worst,
+        }
+    }
+
+    /// Converts the collection into an [`AxResult`], with the representative
+    /// error picked according to `policy`.
+    pub fn into_result(self, policy: PickPolicy) -> AxResult {
+        self.pick(policy).map_or(Ok(()), Err)
+    }
+}
+
+impl<const N: usize> Default for AxErrors<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, E: Into<AxError>> Extend<E> for AxErrors<N> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|e| self.push(e));
+    }
+}
+
+impl<const N: usize> fmt::Display for AxErrors<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_errors(f, self.len, self.iter())
+    }
+}
+
+/// A growable collection of errors.
+///
+/// This is the heap-allocated counterpart of [`AxErrors`], which stores every
+/// recorded error.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct AxErrorVec {
+    errors: Vec<AxError>,
+}
+
+#[cfg(feature = "alloc")]
+impl AxErrorVec {
+    /// Creates an empty collection.
+    pub const fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Adds an error to the collection.
+    pub fn push(&mut self, err: impl Into<AxError>) {
+        self.errors.push(err.into());
+    }
+
+    /// Adds the error of `res` to the collection, if any, and returns its
+    /// value otherwise.
+    pub fn record<T, E: Into<AxError>>(&mut self, res: Result<T, E>) -> Option<T> {
+        res.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Returns the number of recorded errors.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if no error has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the recorded errors, in recording order.
+    pub fn as_slice(&self) -> &[AxError] {
+        &self.errors
+    }
+
+    /// Returns an iterator over the recorded errors, in recording order.
+    pub fn iter(&self) -> impl Iterator<Item = AxError> + '_ {
+        self.errors.iter().copied()
+    }
+
+    /// Returns the representative error according to `policy`, or `None` if
+    /// no error has been recorded.
+    pub fn pick(&self, policy: PickPolicy) -> Option<AxError> {
+        match policy {
+            PickPolicy::First => self.errors.first().copied(),
+            PickPolicy::Last => self.errors.last().copied(),
+            PickPolicy::MostSevere => self.iter().fold(None, |worst, e| {
+                if more_severe(e, worst) {
+                    Some(e)
+                } else {
+                    worst
+                }
+            }),
+        }
+    }
+
+    /// Converts the collection into an [`AxResult`], with the representative
+    /// error picked according to `policy`.
+    pub fn into_result(self, policy: PickPolicy) -> AxResult {
+        self.pick(policy).map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Into<AxError>> Extend<E> for AxErrorVec {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|e| self.push(e));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Into<AxError>> FromIterator<E> for AxErrorVec {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut errors = Self::new();
+        errors.extend(iter);
+        errors
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for AxErrorVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_errors(f, self.len(), self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinuxError;
+
+    #[test]
+    fn test_truncated() {
+        let mut errors = AxErrors::<2>::new();
+        assert_eq!(errors.into_result(PickPolicy::First), Ok(()));
+        assert_eq!(errors.to_string(), "no errors");
+
+        errors.extend([AxError::WouldBlock, AxError::NotFound]);
+        errors.push(LinuxError::ENOSPC);
+        errors.push(AxError::TimedOut);
+        assert!(errors.is_truncated());
+        assert_eq!(errors.len(), 4);
+        assert_eq!(errors.pick(PickPolicy::First), Some(AxError::WouldBlock));
+        assert_eq!(errors.pick(PickPolicy::Last), Some(AxError::TimedOut));
+        assert_eq!(
+            errors.pick(PickPolicy::MostSevere),
+            Some(LinuxError::ENOSPC.into())
+        );
+        #[cfg(not(feature = "no-descriptions"))]
+        assert_eq!(
+            errors.to_string(),
+            "4 errors: [1] Operation would block; [2] Entity not found; ... and 2 more"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Error sequence counters, a port of Linux's `errseq_t`.
+//!
+//! An [`ErrSeq`] records the latest error of an object (e.g. write-back errors
+//! of an address space) together with a sequence counter. Each observer (e.g.
+//! an open file) takes an [`ErrSeqSnapshot`] with [`ErrSeq::sample`] and later
+//! checks it with [`ErrSeq::check_and_advance`], so that every observer sees
+//! each error exactly once, no matter when the error occurred.
+//!
+//! The word is laid out as follows:
+//!
+//! - bits 0..12: the magnitude of the [`AxError`] code;
+//! - bit 12: set if the code is positive, i.e. an [`AxErrorKind`];
+//! - bit 13: the "seen" flag, set once the current error has been reported;
+//! - bits 14..32: the sequence counter, bumped when a new error is recorded
+//!   after the previous one has been seen.
+//!
+//! [`AxErrorKind`]: crate::AxErrorKind
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::{AxError, AxResult};
+
+const CODE_MASK: u32 = (1 << 12) - 1;
+const KIND_FLAG: u32 = 1 << 12;
+const ERR_MASK: u32 = CODE_MASK | KIND_FLAG;
+const SEEN: u32 = 1 << 13;
+const CTR_INC: u32 = 1 << 14;
+
+const fn encode(err: AxError) -> u32 {
+    let code = err.code();
+    debug_assert!(code.unsigned_abs() <= CODE_MASK);
+    if code > 0 {
+        code as u32 | KIND_FLAG
+    } else {
+        code.unsigned_abs()
+    }
+}
+
+const fn decode(word: u32) -> Option<AxError> {
+    let code = (word & CODE_MASK) as i32;
+    if code == 0 {
+        None
+    } else if word & KIND_FLAG != 0 {
+        AxError::from_code(code)
+    } else {
+        AxError::from_code(-code)
+    }
+}
+
+/// A snapshot of an [`ErrSeq`], taken by [`ErrSeq::sample`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ErrSeqSnapshot(u32);
+
+/// An error sequence counter.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, ErrSeq};
+/// let wb_err = ErrSeq::new();
+/// let mut file1 = wb_err.sample();
+/// let mut file2 = wb_err.sample();
+///
+/// wb_err.set(AxError::Io);
+///
+/// // Every file sees the error exactly once.
+/// assert_eq!(wb_err.check_and_advance(&mut file1), Err(AxError::Io));
+/// assert_eq!(wb_err.check_and_advance(&mut file1), Ok(()));
+/// assert_eq!(wb_err.check_and_advance(&mut file2), Err(AxError::Io));
+///
+/// // A file opened after the error has been seen does not see it.
+/// let mut file3 = wb_err.sample();
+/// assert_eq!(wb_err.check_and_advance(&mut file3), Ok(()));
+/// ```
+#[derive(Debug, Default)]
+pub struct ErrSeq(AtomicU32);
+
+impl ErrSeq {
+    /// Creates a new error sequence counter with no error recorded.
+    pub const fn new() -> Self {
+        Self(AtomicU32::new(0))
+    }
+
+    /// Records an error.
+    ///
+    /// If the previous error has been seen, the sequence counter is bumped so
+    /// that all observers see the new error. Returns the resulting snapshot.
+    pub fn set(&self, err: impl Into<AxError>) -> ErrSeqSnapshot {
+        let err = encode(err.into());
+        let mut old = self.0.load(Ordering::Acquire);
+        loop {
+            let mut new = (old & !(ERR_MASK | SEEN)) | err;
+            if old & SEEN != 0 {
+                new = new.wrapping_add(CTR_INC);
+            }
+            if new == old {
+                return ErrSeqSnapshot(new);
+            }
+            match self
+                .0
+                .compare_exchange(old, new, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return ErrSeqSnapshot(new),
+                // Someone else recorded the same error concurrently.
+                Err(cur) if cur == new => return ErrSeqSnapshot(new),
+                Err(cur) => old = cur,
+            }
+        }
+    }
+
+    /// Takes a snapshot of the current state.
+    ///
+    /// If the current error has not been seen by anyone yet, the snapshot is
+    /// taken as if no error had occurred, so that the new observer reports it.
+    pub fn sample(&self) -> ErrSeqSnapshot {
+        let old = self.0.load(Ordering::Acquire);
+        if old & SEEN == 0 {
+            ErrSeqSnapshot(0)
+        } else {
+            ErrSeqSnapshot(old)
+        }
+    }
+
+    /// Returns the current error if it has been recorded since `since`.
+    ///
+    /// This does not mark the error as seen.
+    pub fn check(&self, since: ErrSeqSnapshot) -> AxResult {
+        let cur = self.0.load(Ordering::Acquire);
+        if cur == since.0 {
+            return Ok(());
+        }
+        decode(cur).map_or(Ok(()), Err)
+    }
+
+    /// Returns the current error if it has been recorded since `since`, then
+    /// marks it as seen and advances `since` to the current state.
+    pub fn check_and_advance(&self, since: &mut ErrSeqSnapshot) -> AxResult {
+        let old = self.0.load(Ordering::Acquire);
+        if old == since.0 {
+            return Ok(());
+        }
+        let new = old | SEEN;
+        if new != old {
+            // If this fails, a new error has been recorded concurrently, which
+            // will be reported by the next check.
+            let _ = self
+                .0
+                .compare_exchange(old, new, Ordering::AcqRel, Ordering::Relaxed);
+        }
+        since.0 = new;
+        decode(new).map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinuxError;
+
+    #[test]
+    fn test_errseq() {
+        let seq = ErrSeq::new();
+        let mut since = seq.sample();
+        assert_eq!(seq.check(since), Ok(()));
+
+        seq.set(LinuxError::ENOSPC);
+        seq.set(AxError::Io);
+        // Unseen errors are overwritten without bumping the counter.
+        assert_eq!(seq.check(since), Err(AxError::Io));
+        assert_eq!(seq.check_and_advance(&mut since), Err(AxError::Io));
+
+        let mut late = seq.sample();
+        assert_eq!(seq.check_and_advance(&mut late), Ok(()));
+
+        seq.set(LinuxError::ENOSPC);
+        assert_eq!(
+            seq.check_and_advance(&mut late),
+            Err(LinuxError::ENOSPC.into())
+        );
+        assert_eq!(
+            seq.check_and_advance(&mut since),
+            Err(LinuxError::ENOSPC.into())
+        );
+        assert_eq!(seq.check_and_advance(&mut since), Ok(()));
+
+        // The same error recorded again after being seen is reported again.
+        seq.set(LinuxError::ENOSPC);
+        assert_eq!(
+            seq.check_and_advance(&mut since),
+            Err(LinuxError::ENOSPC.into())
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! C interface for C components linked into the kernel.
+//!
+//! With the `ffi` feature, the functions and statics below are exported with
+//! unmangled names. [`C_HEADER`] holds a C header declaring them along with
+//! `enum ax_error_kind`, whose values match [`AxErrorKind::code`]. A build
+//! script of a crate compiling C code can write it next to its sources:
+//!
+//! ```no_run
+//! std::fs::write("include/axerrno.h", axerrno::ffi::C_HEADER).unwrap();
+//! ```
+//!
+//! The header is also written to the directory passed to build scripts of
+//! dependent crates in the `DEP_AXERRNO_INCLUDE` environment variable.
+//!
+//! The header does not declare `sys_errlist` and `sys_nerr`, as C libraries
+//! may declare them differently.
+
+#![allow(unsafe_code)]
+
+use core::ffi::{c_char, c_int, CStr};
+
+use crate::{AxErrorKind, LinuxError};
+
+/// The generated C header.
+pub const C_HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/axerrno.h"));
+
+/// Returned by [`ax_strerror`] for unknown error numbers.
+const UNKNOWN_ERROR: &CStr = c"Unknown error";
+
+/// Returned by [`ax_strerror`] for `0`.
+const SUCCESS: &CStr = c"Success";
+
+/// The number of entries in [`sys_errlist`].
+const SYS_NERR: usize = LinuxError::EHWPOISON as usize + 1;
+
+/// A pointer to a static C string.
+#[doc(hidden)]
+#[repr(transparent)]
+pub struct StaticCStr(*const c_char);
+
+// SAFETY: the pointer refers to an immutable static string.
+unsafe impl Sync for StaticCStr {}
+
+const fn errlist() -> [StaticCStr; SYS_NERR] {
+    let mut list = [const { StaticCStr(UNKNOWN_ERROR.as_ptr()) }; SYS_NERR];
+    list[0] = StaticCStr(SUCCESS.as_ptr());
+    let mut code = 1;
+    while code < SYS_NERR {
+        if let Some(err) = LinuxError::from_code(code as i32) {
+            list[code] = StaticCStr(err.desc_with_nul().as_ptr().cast());
+        }
+        code += 1;
+    }
+    list
+}
+
+/// The descriptions of all user-visible error numbers, indexed by error
+/// number, compatible with the legacy C library array.
+#[no_mangle]
+#[allow(non_upper_case_globals)]
+pub static sys_errlist: [StaticCStr; SYS_NERR] = errlist();
+
+/// The number of entries in [`sys_errlist`].
+#[no_mangle]
+#[allow(non_upper_case_globals)]
+pub static sys_nerr: c_int = SYS_NERR as c_int;
+
+/// Returns the description of the error number `errnum` as a static C
+/// string, `"Success"` for `0`, or `"Unknown error"` if there is no such
+/// error.
+#[no_mangle]
+pub extern "C" fn ax_strerror(errnum: c_int) -> *const c_char {
+    match LinuxError::from_code(errnum) {
+        Some(err) => err.desc_with_nul().as_ptr().cast(),
+        None if errnum == 0 => SUCCESS.as_ptr(),
+        None => UNKNOWN_ERROR.as_ptr(),
+    }
+}
+
+/// Returns the symbolic name of the error number `errnum`, e.g. `"ENOENT"`,
+/// as a static C string, or `NULL` if there is no such error.
+#[no_mangle]
+pub extern "C" fn ax_errno_name(errnum: c_int) -> *const c_char {
+    match LinuxError::from_code(errnum) {
+        Some(err) => err.name_with_nul().as_ptr().cast(),
+        None => core::ptr::null(),
+    }
+}
+
+/// Returns the error number with the symbolic name `name`, e.g. `"ENOENT"`,
+/// or `0` if there is no such error.
+///
+/// # Safety
+///
+/// `name` must be `NULL` or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn ax_errno_from_name(name: *const c_char) -> c_int {
+    if name.is_null() {
+        return 0;
+    }
+    // SAFETY: guaranteed by the caller.
+    let name = unsafe { CStr::from_ptr(name) };
+    name.to_str()
+        .ok()
+        .and_then(LinuxError::from_name)
+        .map_or(0, LinuxError::code)
+}
+
+/// Returns the error number corresponding to the error kind `kind`, a value
+/// of `enum ax_error_kind`, or `0` if there is no such error kind.
+#[no_mangle]
+pub extern "C" fn ax_kind_to_errno(kind: c_int) -> c_int {
+    AxErrorKind::from_code(kind).map_or(0, |kind| kind.to_linux().code())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ffi() {
+        let desc = unsafe { CStr::from_ptr(ax_strerror(2)) };
+        assert_eq!(desc.to_str(), Ok(LinuxError::ENOENT.as_str()));
+        assert_eq!(unsafe { CStr::from_ptr(ax_strerror(0)) }, SUCCESS);
+        assert_eq!(unsafe { CStr::from_ptr(ax_strerror(-1)) }, UNKNOWN_ERROR);
+        assert_eq!(unsafe { CStr::from_ptr(ax_errno_name(110)) }, c"ETIMEDOUT");
+        assert!(ax_errno_name(41).is_null());
+        assert_eq!(unsafe { ax_errno_from_name(c"EPROBE_DEFER".as_ptr()) }, 517);
+        assert_eq!(unsafe { ax_errno_from_name(c"NotFound".as_ptr()) }, 0);
+        assert_eq!(unsafe { ax_errno_from_name(core::ptr::null()) }, 0);
+        assert_eq!(ax_kind_to_errno(AxErrorKind::NotFound.code()), 2);
+        assert_eq!(ax_kind_to_errno(0), 0);
+
+        assert_eq!(sys_nerr, 134);
+        let desc = unsafe { CStr::from_ptr(sys_errlist[22].0) };
+        assert_eq!(desc.to_str(), Ok(LinuxError::EINVAL.as_str()));
+        assert_eq!(unsafe { CStr::from_ptr(sys_errlist[41].0) }, UNKNOWN_ERROR);
+        assert!(C_HEADER.contains("AX_NOT_FOUND = 30,"));
+    }
+
+    #[test]
+    fn test_c_header_kinds() {
+        let body = C_HEADER
+            .split_once("enum ax_error_kind {\n")
+            .and_then(|(_, rest)| rest.split_once("};"))
+            .unwrap()
+            .0;
+        let mut count = 0;
+        for line in body.lines() {
+            let (name, code) = line.trim().trim_end_matches(',').split_once(" = ").unwrap();
+            let kind = AxErrorKind::from_code(code.parse().unwrap()).unwrap();
+            let expected = format!("AX{}", kind.name().to_ascii_uppercase());
+            assert_eq!(name.replace('_', ""), expected);
+            count += 1;
+        }
+        assert_eq!(count, crate::AX_ERROR_KINDS.len());
+    }
+}
@@ -0,0 +1,34 @@
+//! Set-once global slots used to install pluggable backends.
+
+use spin::Once;
+
+use crate::{AxError, AxResult};
+
+/// A `'static` reference that can be installed exactly once.
+pub(crate) struct GlobalRef<T: ?Sized + 'static>(Once<&'static T>);
+
+impl<T: ?Sized> GlobalRef<T> {
+    pub(crate) const fn new() -> Self {
+        Self(Once::new())
+    }
+
+    /// Installs `value`, or returns [`AxError::AlreadyExists`] if a value has
+    /// already been installed.
+    pub(crate) fn set(&self, value: &'static T) -> AxResult {
+        let mut installed = false;
+        self.0.call_once(|| {
+            installed = true;
+            value
+        });
+        if installed {
+            Ok(())
+        } else {
+            Err(AxError::AlreadyExists)
+        }
+    }
+
+    /// Returns the installed value, if any.
+    pub(crate) fn get(&self) -> Option<&'static T> {
+        self.0.get().copied()
+    }
+}
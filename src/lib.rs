@@ -1,181 +1,148 @@
 #![cfg_attr(not(test), no_std)]
+#![deny(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::fmt;
 use strum::EnumCount;
 
+pub mod abi;
+mod atomic;
+mod catalog;
+mod context;
+mod display;
+pub mod err_ptr;
+pub mod errno;
+mod errors;
+mod errseq;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod global;
+mod libc_strings;
+mod partial;
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+mod pod;
+#[cfg(feature = "alloc")]
+mod report;
+mod restart;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod set;
+mod strerror;
+mod subset;
+pub mod table;
+
 mod linux_errno {
     include!(concat!(env!("OUT_DIR"), "/linux_errno.rs"));
 }
 
+pub use atomic::AtomicAxError;
+pub use catalog::{
+    message_catalog, set_message_catalog, English, MessageCatalog, SimplifiedChinese,
+};
+pub use context::{
+    context, current_context, set_context_storage, ContextChain, ContextGuard, ContextStack,
+    ContextStorage,
+};
+pub use display::{Kernel, Numeric, Strace, Symbolic};
+pub use err_ptr::ErrPtr;
+#[cfg(feature = "alloc")]
+pub use errors::AxErrorVec;
+pub use errors::{AxErrors, PickPolicy};
+pub use errseq::{ErrSeq, ErrSeqSnapshot};
+pub use libc_strings::{Glibc, Musl};
 pub use linux_errno::LinuxError;
+pub use partial::{AxPartial, PartialResult};
+#[cfg(feature = "alloc")]
+pub use report::{AxReport, WrapErr};
+pub use restart::SyscallRestart;
+pub use set::AxErrorSet;
+pub use strerror::strerror_r;
 
-/// The error kind type used by ArceOS.
-///
-/// Similar to [`std::io::ErrorKind`].
-///
-/// [`std::io::ErrorKind`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html
-#[repr(i32)]
-#[non_exhaustive]
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, EnumCount)]
-pub enum AxErrorKind {
-    /// A socket address could not be bound because the address is already in use elsewhere.
-    AddrInUse = 1,
-    /// The socket is already connected.
-    AlreadyConnected,
-    /// An entity already exists, often a file.
-    AlreadyExists,
-    /// Program argument list too long.
-    ArgumentListTooLong,
-    /// Bad address.
-    BadAddress,
-    /// Bad file descriptor.
-    BadFileDescriptor,
-    /// Bad internal state.
-    BadState,
-    /// Broken pipe
-    BrokenPipe,
-    /// The connection was refused by the remote server.
-    ConnectionRefused,
-    /// The connection was reset by the remote server.
-    ConnectionReset,
-    /// Cross-device or cross-filesystem (hard) link or rename.
-    CrossesDevices,
-    /// A non-empty directory was specified where an empty directory was expected.
-    DirectoryNotEmpty,
-    /// Loop in the filesystem or IO subsystem; often, too many levels of
-    /// symbolic links.
-    FilesystemLoop,
-    /// Illegal byte sequence.
-    IllegalBytes,
-    /// The operation was partially successful and needs to be checked later on
-    /// due to not blocking.
-    InProgress,
-    /// This operation was interrupted.
-    Interrupted,
-    /// Data not valid for the operation were encountered.
-    ///
-    /// Unlike [`InvalidInput`], this typically means that the operation
-    /// parameters were valid, however the error was caused by malformed
-    /// input data.
-    ///
-    /// For example, a function that reads a file into a string will error with
-    /// `InvalidData` if the file's contents are not valid UTF-8.
-    ///
-    /// [`InvalidInput`]: AxErrorKind::InvalidInput
-    InvalidData,
-    /// Invalid executable format.
-    InvalidExecutable,
-    /// Invalid parameter/argument.
-    InvalidInput,
-    /// Input/output error.
-    Io,
-    /// The filesystem object is, unexpectedly, a directory.
-    IsADirectory,
-    /// Filename is too long.
-    NameTooLong,
-    /// Not enough space/cannot allocate memory.
-    NoMemory,
-    /// No such device.
-    NoSuchDevice,
-    /// No such process.
-    NoSuchProcess,
-    /// A filesystem object is, unexpectedly, not a directory.
-    NotADirectory,
-    /// The specified entity is not a socket.
-    NotASocket,
-    /// Not a typewriter.
-    NotATty,
-    /// The network operation failed because it was not connected yet.
-    NotConnected,
-    /// The requested entity is not found.
-    NotFound,
-    /// Operation not permitted.
-    OperationNotPermitted,
-    /// Operation not supported.
-    OperationNotSupported,
-    /// Result out of range.
-    OutOfRange,
-    /// The operation lacked the necessary privileges to complete.
-    PermissionDenied,
-    /// The filesystem or storage medium is read-only, but a write operation was attempted.
-    ReadOnlyFilesystem,
-    /// Device or resource is busy.
-    ResourceBusy,
-    /// The underlying storage (typically, a filesystem) is full.
-    StorageFull,
-    /// The I/O operation’s timeout expired, causing it to be canceled.
-    TimedOut,
-    /// The process has too many files open.
-    TooManyOpenFiles,
-    /// An error returned when an operation could not be completed because an
-    /// "end of file" was reached prematurely.
-    UnexpectedEof,
-    /// This operation is unsupported or unimplemented.
-    Unsupported,
-    /// The operation needs to block to complete, but the blocking operation was
-    /// requested to not occur.
-    WouldBlock,
-    /// An error returned when an operation could not be completed because a
-    /// call to `write()` returned [`Ok(0)`](Ok).
-    WriteZero,
-}
+include!(concat!(env!("OUT_DIR"), "/ax_error_kind.rs"));
 
 impl AxErrorKind {
-    /// Returns the error description.
-    pub fn as_str(&self) -> &'static str {
-        use AxErrorKind::*;
-        match *self {
-            AddrInUse => "Address in use",
-            AlreadyConnected => "Already connected",
-            AlreadyExists => "Entity already exists",
-            ArgumentListTooLong => "Argument list too long",
-            BadAddress => "Bad address",
-            BadFileDescriptor => "Bad file descriptor",
-            BadState => "Bad internal state",
-            BrokenPipe => "Broken pipe",
-            ConnectionRefused => "Connection refused",
-            ConnectionReset => "Connection reset",
-            CrossesDevices => "Cross-device link or rename",
-            DirectoryNotEmpty => "Directory not empty",
-            FilesystemLoop => "Filesystem loop or indirection limit",
-            IllegalBytes => "Illegal byte sequence",
-            InProgress => "Operation in progress",
-            Interrupted => "Operation interrupted",
-            InvalidData => "Invalid data",
-            InvalidExecutable => "Invalid executable format",
-            InvalidInput => "Invalid input parameter",
-            Io => "I/O error",
-            IsADirectory => "Is a directory",
-            NameTooLong => "Filename too long",
-            NoMemory => "Out of memory",
-            NoSuchDevice => "No such device",
-            NoSuchProcess => "No such process",
-            NotADirectory => "Not a directory",
-            NotASocket => "Not a socket",
-            NotATty => "Inappropriate ioctl for device",
-            NotConnected => "Not connected",
-            NotFound => "Entity not found",
-            OperationNotPermitted => "Operation not permitted",
-            OperationNotSupported => "Operation not supported",
-            OutOfRange => "Result out of range",
-            PermissionDenied => "Permission denied",
-            ReadOnlyFilesystem => "Read-only filesystem",
-            ResourceBusy => "Resource busy",
-            StorageFull => "No storage space",
-            TimedOut => "Timed out",
-            TooManyOpenFiles => "Too many open files",
-            UnexpectedEof => "Unexpected end of file",
-            Unsupported => "Operation not supported",
-            WouldBlock => "Operation would block",
-            WriteZero => "Write zero",
-        }
+    /// Returns the name of the error kind, e.g. `"NotFound"`.
+    pub const fn name(self) -> &'static str {
+        AX_ERROR_KIND_NAMES[self as usize - 1]
+    }
+
+    /// Returns the built-in English error description. See
+    /// [`message`](AxErrorKind::message) for the localized one.
+    ///
+    /// With the `no-descriptions` feature, this is the same as
+    /// [`name`](AxErrorKind::name).
+    #[cfg(feature = "no-descriptions")]
+    pub const fn as_str(&self) -> &'static str {
+        self.name()
+    }
+
+    /// Returns the built-in English error description. See
+    /// [`message`](AxErrorKind::message) for the localized one.
+    ///
+    /// With the `no-descriptions` feature, this is the same as
+    /// [`name`](AxErrorKind::name).
+    #[cfg(not(feature = "no-descriptions"))]
+    pub const fn as_str(&self) -> &'static str {
+        AX_ERROR_KIND_DESCS[*self as usize - 1]
     }
 
     /// Returns the error code value in `i32`.
     pub const fn code(self) -> i32 {
         self as i32
     }
+
+    /// Returns the error kind with the given code, or `None` if there is no
+    /// such kind.
+    ///
+    /// This is the `const` version of [`TryFrom<i32>`](AxErrorKind::try_from).
+    pub const fn from_code(value: i32) -> Option<Self> {
+        if value > 0 && value <= AxErrorKind::COUNT as i32 {
+            Some(AX_ERROR_KINDS[value as usize - 1])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the error kind with the given name, e.g. `"NotFound"`, or
+    /// `None` if there is no such kind.
+    pub fn from_name(name: &str) -> Option<Self> {
+        AX_ERROR_KINDS
+            .iter()
+            .copied()
+            .find(|kind| kind.name() == name)
+    }
+
+    /// Converts to the corresponding [`LinuxError`].
+    ///
+    /// This is the `const` version of [`From<AxErrorKind>`](LinuxError::from).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxErrorKind, LinuxError};
+    /// const EAGAIN: LinuxError = AxErrorKind::WouldBlock.to_linux();
+    /// assert_eq!(EAGAIN, LinuxError::EAGAIN);
+    /// ```
+    pub const fn to_linux(self) -> LinuxError {
+        AX_ERROR_KIND_LINUX[self as usize - 1]
+    }
+
+    /// Converts from the corresponding [`LinuxError`], or returns `None` if
+    /// there is no such error kind.
+    ///
+    /// This is the `const` version of
+    /// [`TryFrom<LinuxError>`](AxErrorKind::try_from).
+    pub const fn from_linux(e: LinuxError) -> Option<Self> {
+        let code = e.code() as usize;
+        if code < LINUX_ERROR_KINDS.len() {
+            Self::from_code(LINUX_ERROR_KINDS[code] as i32)
+        } else {
+            None
+        }
+    }
 }
 
 impl TryFrom<i32> for AxErrorKind {
@@ -183,66 +150,25 @@ impl TryFrom<i32> for AxErrorKind {
 
     #[inline]
     fn try_from(value: i32) -> Result<Self, Self::Error> {
-        if value > 0 && value <= AxErrorKind::COUNT as i32 {
-            Ok(unsafe { core::mem::transmute::<i32, AxErrorKind>(value) })
-        } else {
-            Err(value)
-        }
+        AxErrorKind::from_code(value).ok_or(value)
     }
 }
 
 impl fmt::Display for AxErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        if f.alternate() {
+            display::fmt_name_desc(f, self.name(), self.message())
+        } else {
+            write!(f, "{}", self.message())
+        }
     }
 }
 
+impl core::error::Error for AxErrorKind {}
+
 impl From<AxErrorKind> for LinuxError {
     fn from(e: AxErrorKind) -> Self {
-        use AxErrorKind::*;
-        use LinuxError::*;
-        match e {
-            AddrInUse => EADDRINUSE,
-            AlreadyConnected => EISCONN,
-            AlreadyExists => EEXIST,
-            ArgumentListTooLong => E2BIG,
-            BadAddress | BadState => EFAULT,
-            BadFileDescriptor => EBADF,
-            BrokenPipe => EPIPE,
-            ConnectionRefused => ECONNREFUSED,
-            ConnectionReset => ECONNRESET,
-            CrossesDevices => EXDEV,
-            DirectoryNotEmpty => ENOTEMPTY,
-            FilesystemLoop => ELOOP,
-            IllegalBytes => EILSEQ,
-            InProgress => EINPROGRESS,
-            Interrupted => EINTR,
-            InvalidExecutable => ENOEXEC,
-            InvalidInput | InvalidData => EINVAL,
-            Io => EIO,
-            IsADirectory => EISDIR,
-            NameTooLong => ENAMETOOLONG,
-            NoMemory => ENOMEM,
-            NoSuchDevice => ENODEV,
-            NoSuchProcess => ESRCH,
-            NotADirectory => ENOTDIR,
-            NotASocket => ENOTSOCK,
-            NotATty => ENOTTY,
-            NotConnected => ENOTCONN,
-            NotFound => ENOENT,
-            OperationNotPermitted => EPERM,
-            OperationNotSupported => EOPNOTSUPP,
-            OutOfRange => ERANGE,
-            PermissionDenied => EACCES,
-            ReadOnlyFilesystem => EROFS,
-            ResourceBusy => EBUSY,
-            StorageFull => ENOSPC,
-            TimedOut => ETIMEDOUT,
-            TooManyOpenFiles => EMFILE,
-            UnexpectedEof | WriteZero => EIO,
-            Unsupported => ENOSYS,
-            WouldBlock => EAGAIN,
-        }
+        e.to_linux()
     }
 }
 
@@ -250,59 +176,25 @@ impl TryFrom<LinuxError> for AxErrorKind {
     type Error = LinuxError;
 
     fn try_from(e: LinuxError) -> Result<Self, Self::Error> {
-        use AxErrorKind::*;
-        use LinuxError::*;
-        Ok(match e {
-            EADDRINUSE => AddrInUse,
-            EISCONN => AlreadyConnected,
-            EEXIST => AlreadyExists,
-            E2BIG => ArgumentListTooLong,
-            EFAULT => BadAddress,
-            EBADF => BadFileDescriptor,
-            EPIPE => BrokenPipe,
-            ECONNREFUSED => ConnectionRefused,
-            ECONNRESET => ConnectionReset,
-            EXDEV => CrossesDevices,
-            ENOTEMPTY => DirectoryNotEmpty,
-            ELOOP => FilesystemLoop,
-            EILSEQ => IllegalBytes,
-            EINPROGRESS => InProgress,
-            EINTR => Interrupted,
-            ENOEXEC => InvalidExecutable,
-            EINVAL => InvalidInput,
-            EIO => Io,
-            EISDIR => IsADirectory,
-            ENAMETOOLONG => NameTooLong,
-            ENOMEM => NoMemory,
-            ENODEV => NoSuchDevice,
-            ESRCH => NoSuchProcess,
-            ENOTDIR => NotADirectory,
-            ENOTSOCK => NotASocket,
-            ENOTTY => NotATty,
-            ENOTCONN => NotConnected,
-            ENOENT => NotFound,
-            EPERM => OperationNotPermitted,
-            EOPNOTSUPP => OperationNotSupported,
-            ERANGE => OutOfRange,
-            EACCES => PermissionDenied,
-            EROFS => ReadOnlyFilesystem,
-            EBUSY => ResourceBusy,
-            ENOSPC => StorageFull,
-            ETIMEDOUT => TimedOut,
-            EMFILE => TooManyOpenFiles,
-            ENOSYS => Unsupported,
-            EAGAIN => WouldBlock,
-            _ => {
-                return Err(e);
-            }
-        })
+        AxErrorKind::from_linux(e).ok_or(e)
     }
 }
 
+include!(concat!(env!("OUT_DIR"), "/ax_error_code.rs"));
+
 /// The error type used by ArceOS.
 #[repr(transparent)]
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct AxError(i32);
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::IntoBytes,
+        zerocopy::TryFromBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+pub struct AxError(AxErrorCode);
 
 enum AxErrorData {
     Ax(AxErrorKind),
@@ -310,25 +202,74 @@ enum AxErrorData {
 }
 
 impl AxError {
-    const fn new_ax(kind: AxErrorKind) -> Self {
-        AxError(kind.code())
+    /// Creates an error from an [`AxErrorKind`].
+    pub const fn from_kind(kind: AxErrorKind) -> Self {
+        AxError(AxErrorCode::from_kind(kind))
+    }
+
+    /// Creates an error from a [`LinuxError`].
+    pub const fn from_linux(kind: LinuxError) -> Self {
+        AxError(AxErrorCode::from_linux(kind))
+    }
+
+    /// Returns the error with the given code, or `None` if the code is not
+    /// valid.
+    ///
+    /// This is the `const` version of [`TryFrom<i32>`](AxError::try_from).
+    pub const fn from_code(value: i32) -> Option<Self> {
+        match AxErrorCode::from_code(value) {
+            Some(code) => Some(AxError(code)),
+            None => None,
+        }
     }
 
-    const fn new_linux(kind: LinuxError) -> Self {
-        AxError(-kind.code())
+    /// Returns the error with the given name, which is either an
+    /// [`AxErrorKind`] name like `"NotFound"` or a [`LinuxError`] name like
+    /// `"ENOENT"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{AxError, LinuxError};
+    /// assert_eq!(AxError::from_name("NotFound"), Some(AxError::NotFound));
+    /// assert_eq!(AxError::from_name("ENOENT"), Some(LinuxError::ENOENT.into()));
+    /// assert_eq!(AxError::from_name("enoent"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match AxErrorKind::from_name(name) {
+            Some(kind) => Some(Self::from_kind(kind)),
+            None => LinuxError::from_name(name).map(Self::from_linux),
+        }
     }
 
     const fn data(&self) -> AxErrorData {
-        if self.0 < 0 {
-            AxErrorData::Linux(unsafe { core::mem::transmute::<i32, LinuxError>(-self.0) })
+        // An `AxError` always holds a valid code.
+        let code = self.code();
+        if code < 0 {
+            match LinuxError::from_code(-code) {
+                Some(e) => AxErrorData::Linux(e),
+                None => unreachable!(),
+            }
         } else {
-            AxErrorData::Ax(unsafe { core::mem::transmute::<i32, AxErrorKind>(self.0) })
+            match AxErrorKind::from_code(code) {
+                Some(kind) => AxErrorData::Ax(kind),
+                None => unreachable!(),
+            }
         }
     }
 
     /// Returns the error code value in `i32`.
     pub const fn code(self) -> i32 {
-        self.0
+        self.0 as i32
+    }
+
+    /// Returns the symbolic name of the error, e.g. `"NotFound"` for an
+    /// [`AxErrorKind`] or `"ENOENT"` for a [`LinuxError`].
+    pub const fn name(self) -> &'static str {
+        match self.data() {
+            AxErrorData::Ax(kind) => kind.name(),
+            AxErrorData::Linux(e) => e.name(),
+        }
     }
 
     /// Returns a canonicalized version of this error.
@@ -344,29 +285,51 @@ impl AxError {
     /// let canonical_err = linux_err.canonicalize();
     /// assert_eq!(canonical_err, AxError::from(AxErrorKind::PermissionDenied));
     /// ```
-    pub fn canonicalize(self) -> Self {
-        AxErrorKind::try_from(self).map_or_else(Into::into, Into::into)
+    pub const fn canonicalize(self) -> Self {
+        match self.kind() {
+            Some(kind) => AxError::from_kind(kind),
+            None => self,
+        }
+    }
+
+    /// Returns the corresponding [`AxErrorKind`], or `None` if this is a
+    /// [`LinuxError`] without a corresponding kind.
+    ///
+    /// This is the `const` version of
+    /// [`TryFrom<AxError>`](AxErrorKind::try_from).
+    pub const fn kind(self) -> Option<AxErrorKind> {
+        match self.data() {
+            AxErrorData::Ax(kind) => Some(kind),
+            AxErrorData::Linux(e) => AxErrorKind::from_linux(e),
+        }
+    }
+
+    /// Converts to the corresponding [`LinuxError`].
+    ///
+    /// This is the `const` version of [`From<AxError>`](LinuxError::from).
+    pub const fn to_linux(self) -> LinuxError {
+        match self.data() {
+            AxErrorData::Ax(kind) => kind.to_linux(),
+            AxErrorData::Linux(e) => e,
+        }
     }
 }
 
 impl<E: Into<AxErrorKind>> From<E> for AxError {
     fn from(e: E) -> Self {
-        AxError::new_ax(e.into())
+        AxError::from_kind(e.into())
     }
 }
 
 impl From<LinuxError> for AxError {
     fn from(e: LinuxError) -> Self {
-        AxError::new_linux(e)
+        AxError::from_linux(e)
     }
 }
 
 impl From<AxError> for LinuxError {
     fn from(e: AxError) -> Self {
-        match e.data() {
-            AxErrorData::Ax(kind) => LinuxError::from(kind),
-            AxErrorData::Linux(kind) => kind,
-        }
+        e.to_linux()
     }
 }
 
@@ -374,10 +337,7 @@ impl TryFrom<AxError> for AxErrorKind {
     type Error = LinuxError;
 
     fn try_from(e: AxError) -> Result<Self, Self::Error> {
-        match e.data() {
-            AxErrorData::Ax(kind) => Ok(kind),
-            AxErrorData::Linux(e) => e.try_into(),
-        }
+        e.kind().ok_or_else(|| e.to_linux())
     }
 }
 
@@ -385,11 +345,7 @@ impl TryFrom<i32> for AxError {
     type Error = i32;
 
     fn try_from(value: i32) -> Result<Self, Self::Error> {
-        if AxErrorKind::try_from(value).is_ok() || LinuxError::try_from(-value).is_ok() {
-            Ok(AxError(value))
-        } else {
-            Err(value)
-        }
+        AxError::from_code(value).ok_or(value)
     }
 }
 
@@ -402,72 +358,26 @@ impl fmt::Debug for AxError {
     }
 }
 
-impl fmt::Display for AxError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+#[cfg(feature = "defmt")]
+impl defmt::Format for AxError {
+    fn format(&self, f: defmt::Formatter<'_>) {
         match self.data() {
-            AxErrorData::Ax(kind) => write!(f, "{}", kind),
-            AxErrorData::Linux(kind) => write!(f, "{}", kind),
+            AxErrorData::Ax(kind) => defmt::write!(f, "AxErrorKind::{}", kind),
+            AxErrorData::Linux(kind) => defmt::write!(f, "LinuxError::{}", kind),
         }
     }
 }
 
-macro_rules! axerror_consts {
-    ($($name:ident),*) => {
-        #[allow(non_upper_case_globals)]
-        impl AxError {
-            $(
-                #[doc = concat!("An [`AxError`] with kind [`AxErrorKind::", stringify!($name), "`].")]
-                pub const $name: Self = Self::new_ax(AxErrorKind::$name);
-            )*
+impl fmt::Display for AxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.data() {
+            AxErrorData::Ax(kind) => fmt::Display::fmt(&kind, f),
+            AxErrorData::Linux(kind) => fmt::Display::fmt(&kind, f),
         }
-    };
+    }
 }
 
-axerror_consts!(
-    AddrInUse,
-    AlreadyConnected,
-    AlreadyExists,
-    ArgumentListTooLong,
-    BadAddress,
-    BadFileDescriptor,
-    BadState,
-    BrokenPipe,
-    ConnectionRefused,
-    ConnectionReset,
-    CrossesDevices,
-    DirectoryNotEmpty,
-    FilesystemLoop,
-    IllegalBytes,
-    InProgress,
-    Interrupted,
-    InvalidData,
-    InvalidExecutable,
-    InvalidInput,
-    Io,
-    IsADirectory,
-    NameTooLong,
-    NoMemory,
-    NoSuchDevice,
-    NoSuchProcess,
-    NotADirectory,
-    NotASocket,
-    NotATty,
-    NotConnected,
-    NotFound,
-    OperationNotPermitted,
-    OperationNotSupported,
-    OutOfRange,
-    PermissionDenied,
-    ReadOnlyFilesystem,
-    ResourceBusy,
-    StorageFull,
-    TimedOut,
-    TooManyOpenFiles,
-    UnexpectedEof,
-    Unsupported,
-    WouldBlock,
-    WriteZero
-);
+impl core::error::Error for AxError {}
 
 /// A specialized [`Result`] type with [`AxError`] as the error type.
 pub type AxResult<T = ()> = Result<T, AxError>;
@@ -475,6 +385,12 @@ pub type AxResult<T = ()> = Result<T, AxError>;
 /// Convenience method to construct an [`AxError`] type while printing a warning
 /// message.
 ///
+/// The warning also includes the active [`context`] frames, if any.
+///
+/// With the `defmt-log` feature, the warning is logged with `defmt::warn!`
+/// instead of `log::warn!`, and `$msg` must implement `defmt::Format`. The
+/// calling crate must then also depend on `defmt`.
+///
 /// # Examples
 ///
 /// ```
@@ -498,13 +414,13 @@ macro_rules! ax_err_type {
     ($err: ident) => {{
         use $crate::AxErrorKind::*;
         let err = $crate::AxError::from($err);
-        $crate::__priv::warn!("[{:?}]", err);
+        $crate::__priv::warn_err!(err);
         err
     }};
     ($err: ident, $msg: expr) => {{
         use $crate::AxErrorKind::*;
         let err = $crate::AxError::from($err);
-        $crate::__priv::warn!("[{:?}] {}", err, $msg);
+        $crate::__priv::warn_err!(err, $msg);
         err
     }};
 }
@@ -578,13 +494,46 @@ pub type LinuxResult<T = ()> = Result<T, LinuxError>;
 
 impl fmt::Display for LinuxError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        if f.alternate() {
+            display::fmt_name_desc(f, self.name(), self.message())
+        } else {
+            write!(f, "{}", self.message())
+        }
     }
 }
 
+impl core::error::Error for LinuxError {}
+
 #[doc(hidden)]
 pub mod __priv {
-    pub use log::warn;
+    pub use crate::__ax_warn_err as warn_err;
+    #[cfg(feature = "defmt-log")]
+    pub use defmt;
+    pub use log;
+}
+
+#[cfg(not(feature = "defmt-log"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ax_warn_err {
+    ($err: expr) => {
+        $crate::__priv::log::warn!("[{:?}]{}", $err, $crate::current_context())
+    };
+    ($err: expr, $msg: expr) => {
+        $crate::__priv::log::warn!("[{:?}] {}{}", $err, $msg, $crate::current_context())
+    };
+}
+
+#[cfg(feature = "defmt-log")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ax_warn_err {
+    ($err: expr) => {
+        $crate::__priv::defmt::warn!("[{}]{}", $err, $crate::current_context())
+    };
+    ($err: expr, $msg: expr) => {
+        $crate::__priv::defmt::warn!("[{}] {}{}", $err, $msg, $crate::current_context())
+    };
 }
 
 #[cfg(test)]
@@ -620,4 +569,44 @@ mod tests {
             assert_eq!(LinuxError::from(e), err);
         }
     }
+
+    #[test]
+    fn test_kind_table() {
+        for (i, kind) in crate::AX_ERROR_KINDS.iter().enumerate() {
+            assert_eq!(kind.code(), i as i32 + 1);
+        }
+        for code in [0, 41, 134, 511, 525, i32::MAX, -1, i32::MIN] {
+            assert_eq!(LinuxError::from_code(code), None);
+        }
+        assert_eq!(LinuxError::from_code(1), Some(LinuxError::EPERM));
+        assert_eq!(LinuxError::EPERM.name(), "EPERM");
+        assert_eq!(LinuxError::ENOTSUPP.name(), "ENOTSUPP");
+        assert_eq!(AxErrorKind::AddrInUse.name(), "AddrInUse");
+        assert_eq!(AxErrorKind::WriteZero.name(), "WriteZero");
+        #[cfg(not(feature = "no-descriptions"))]
+        {
+            assert_eq!(LinuxError::EPERM.as_str(), "Operation not permitted");
+            assert_eq!(LinuxError::ENOTSUPP.as_str(), "Operation is not supported");
+        }
+        #[cfg(feature = "no-descriptions")]
+        {
+            assert_eq!(LinuxError::EPERM.as_str(), "EPERM");
+            assert_eq!(AxErrorKind::NotFound.as_str(), "NotFound");
+        }
+    }
+
+    #[test]
+    fn test_const_conversion() {
+        for code in 1..=AxErrorKind::COUNT as i32 {
+            let kind = AxErrorKind::from_code(code).unwrap();
+            let linux = kind.to_linux();
+            assert_eq!(linux, LinuxError::from(kind));
+            assert_eq!(AxErrorKind::from_linux(linux), linux.try_into().ok());
+            let err = AxError::from_linux(linux);
+            assert_eq!(err.kind(), AxErrorKind::try_from(err).ok());
+            assert_eq!(err.to_linux(), linux);
+            assert_eq!(err.canonicalize().kind(), err.kind());
+            assert_eq!(AxError::from_code(-linux.code()), Some(err));
+        }
+    }
 }
@@ -0,0 +1,335 @@
+//! Error descriptions matching the `strerror` output of C libraries.
+//!
+//! The built-in descriptions come from the comments in the Linux kernel
+//! headers, e.g. `Try again` for [`EAGAIN`](LinuxError::EAGAIN), where glibc
+//! and musl print `Resource temporarily unavailable`. Test suites comparing
+//! error text need the descriptions of the C library they were written for.
+
+use crate::{AxErrorKind, LinuxError, MessageCatalog};
+
+impl LinuxError {
+    /// Returns the description printed by glibc's `strerror`.
+    ///
+    /// Kernel-internal errors are unknown to glibc, which prints them as
+    /// `Unknown error <code>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::LinuxError;
+    /// assert_eq!(LinuxError::EAGAIN.as_str_glibc(), "Resource temporarily unavailable");
+    /// assert_eq!(LinuxError::ENOTSUPP.as_str_glibc(), "Unknown error 524");
+    /// ```
+    pub const fn as_str_glibc(self) -> &'static str {
+        use LinuxError::*;
+        match self {
+            EPERM => "Operation not permitted",
+            ENOENT => "No such file or directory",
+            ESRCH => "No such process",
+            EINTR => "Interrupted system call",
+            EIO => "Input/output error",
+            ENXIO => "No such device or address",
+            E2BIG => "Argument list too long",
+            ENOEXEC => "Exec format error",
+            EBADF => "Bad file descriptor",
+            ECHILD => "No child processes",
+            EAGAIN => "Resource temporarily unavailable",
+            ENOMEM => "Cannot allocate memory",
+            EACCES => "Permission denied",
+            EFAULT => "Bad address",
+            ENOTBLK => "Block device required",
+            EBUSY => "Device or resource busy",
+            EEXIST => "File exists",
+            EXDEV => "Invalid cross-device link",
+            ENODEV => "No such device",
+            ENOTDIR => "Not a directory",
+            EISDIR => "Is a directory",
+            EINVAL => "Invalid argument",
+            ENFILE => "Too many open files in system",
+            EMFILE => "Too many open files",
+            ENOTTY => "Inappropriate ioctl for device",
+            ETXTBSY => "Text file busy",
+            EFBIG => "File too large",
+            ENOSPC => "No space left on device",
+            ESPIPE => "Illegal seek",
+            EROFS => "Read-only file system",
+            EMLINK => "Too many links",
+            EPIPE => "Broken pipe",
+            EDOM => "Numerical argument out of domain",
+            ERANGE => "Numerical result out of range",
+            EDEADLK => "Resource deadlock avoided",
+            ENAMETOOLONG => "File name too long",
+            ENOLCK => "No locks available",
+            ENOSYS => "Function not implemented",
+            ENOTEMPTY => "Directory not empty",
+            ELOOP => "Too many levels of symbolic links",
+            ENOMSG => "No message of desired type",
+            EIDRM => "Identifier removed",
+            ECHRNG => "Channel number out of range",
+            EL2NSYNC => "Level 2 not synchronized",
+            EL3HLT => "Level 3 halted",
+            EL3RST => "Level 3 reset",
+            ELNRNG => "Link number out of range",
+            EUNATCH => "Protocol driver not attached",
+            ENOCSI => "No CSI structure available",
+            EL2HLT => "Level 2 halted",
+            EBADE => "Invalid exchange",
+            EBADR => "Invalid request descriptor",
+            EXFULL => "Exchange full",
+            ENOANO => "No anode",
+            EBADRQC => "Invalid request code",
+            EBADSLT => "Invalid slot",
+            EBFONT => "Bad font file format",
+            ENOSTR => "Device not a stream",
+            ENODATA => "No data available",
+            ETIME => "Timer expired",
+            ENOSR => "Out of streams resources",
+            ENONET => "Machine is not on the network",
+            ENOPKG => "Package not installed",
+            EREMOTE => "Object is remote",
+            ENOLINK => "Link has been severed",
+            EADV => "Advertise error",
+            ESRMNT => "Srmount error",
+            ECOMM => "Communication error on send",
+            EPROTO => "Protocol error",
+            EMULTIHOP => "Multihop attempted",
+            EDOTDOT => "RFS specific error",
+            EBADMSG => "Bad message",
+            EOVERFLOW => "Value too large for defined data type",
+            ENOTUNIQ => "Name not unique on network",
+            EBADFD => "File descriptor in bad state",
+            EREMCHG => "Remote address changed",
+            ELIBACC => "Can not access a needed shared library",
+            ELIBBAD => "Accessing a corrupted shared library",
+            ELIBSCN => ".lib section in a.out corrupted",
+            ELIBMAX => "Attempting to link in too many shared libraries",
+            ELIBEXEC => "Cannot exec a shared library directly",
+            EILSEQ => "Invalid or incomplete multibyte or wide character",
+            ERESTART => "Interrupted system call should be restarted",
+            ESTRPIPE => "Streams pipe error",
+            EUSERS => "Too many users",
+            ENOTSOCK => "Socket operation on non-socket",
+            EDESTADDRREQ => "Destination address required",
+            EMSGSIZE => "Message too long",
+            EPROTOTYPE => "Protocol wrong type for socket",
+            ENOPROTOOPT => "Protocol not available",
+            EPROTONOSUPPORT => "Protocol not supported",
+            ESOCKTNOSUPPORT => "Socket type not supported",
+            EOPNOTSUPP => "Operation not supported",
+            EPFNOSUPPORT => "Protocol family not supported",
+            EAFNOSUPPORT => "Address family not supported by protocol",
+            EADDRINUSE => "Address already in use",
+            EADDRNOTAVAIL => "Cannot assign requested address",
+            ENETDOWN => "Network is down",
+            ENETUNREACH => "Network is unreachable",
+            ENETRESET => "Network dropped connection on reset",
+            ECONNABORTED => "Software caused connection abort",
+            ECONNRESET => "Connection reset by peer",
+            ENOBUFS => "No buffer space available",
+            EISCONN => "Transport endpoint is already connected",
+            ENOTCONN => "Transport endpoint is not connected",
+            ESHUTDOWN => "Cannot send after transport endpoint shutdown",
+            ETOOMANYREFS => "Too many references: cannot splice",
+            ETIMEDOUT => "Connection timed out",
+            ECONNREFUSED => "Connection refused",
+            EHOSTDOWN => "Host is down",
+            EHOSTUNREACH => "No route to host",
+            EALREADY => "Operation already in progress",
+            EINPROGRESS => "Operation now in progress",
+            ESTALE => "Stale file handle",
+            EUCLEAN => "Structure needs cleaning",
+            ENOTNAM => "Not a XENIX named type file",
+            ENAVAIL => "No XENIX semaphores available",
+            EISNAM => "Is a named type file",
+            EREMOTEIO => "Remote I/O error",
+            EDQUOT => "Disk quota exceeded",
+            ENOMEDIUM => "No medium found",
+            EMEDIUMTYPE => "Wrong medium type",
+            ECANCELED => "Operation canceled",
+            ENOKEY => "Required key not available",
+            EKEYEXPIRED => "Key has expired",
+            EKEYREVOKED => "Key has been revoked",
+            EKEYREJECTED => "Key was rejected by service",
+            EOWNERDEAD => "Owner died",
+            ENOTRECOVERABLE => "State not recoverable",
+            ERFKILL => "Operation not possible due to RF-kill",
+            EHWPOISON => "Memory page has hardware error",
+            ERESTARTSYS => "Unknown error 512",
+            ERESTARTNOINTR => "Unknown error 513",
+            ERESTARTNOHAND => "Unknown error 514",
+            ENOIOCTLCMD => "Unknown error 515",
+            ERESTART_RESTARTBLOCK => "Unknown error 516",
+            EPROBE_DEFER => "Unknown error 517",
+            ENOTSUPP => "Unknown error 524",
+        }
+    }
+
+    /// Returns the description printed by musl's `strerror`.
+    ///
+    /// Errors unknown to musl, including all kernel-internal errors, are
+    /// printed as `No error information`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::LinuxError;
+    /// assert_eq!(LinuxError::ENOMEM.as_str_musl(), "Out of memory");
+    /// assert_eq!(LinuxError::EL2HLT.as_str_musl(), "No error information");
+    /// ```
+    pub const fn as_str_musl(self) -> &'static str {
+        use LinuxError::*;
+        match self {
+            EILSEQ => "Illegal byte sequence",
+            EDOM => "Domain error",
+            ERANGE => "Result not representable",
+            ENOTTY => "Not a tty",
+            EACCES => "Permission denied",
+            EPERM => "Operation not permitted",
+            ENOENT => "No such file or directory",
+            ESRCH => "No such process",
+            EEXIST => "File exists",
+            EOVERFLOW => "Value too large for data type",
+            ENOSPC => "No space left on device",
+            ENOMEM => "Out of memory",
+            EBUSY => "Resource busy",
+            EINTR => "Interrupted system call",
+            EAGAIN => "Resource temporarily unavailable",
+            ESPIPE => "Invalid seek",
+            EXDEV => "Cross-device link",
+            EROFS => "Read-only file system",
+            ENOTEMPTY => "Directory not empty",
+            ECONNRESET => "Connection reset by peer",
+            ETIMEDOUT => "Operation timed out",
+            ECONNREFUSED => "Connection refused",
+            EHOSTDOWN => "Host is down",
+            EHOSTUNREACH => "Host is unreachable",
+            EADDRINUSE => "Address in use",
+            EPIPE => "Broken pipe",
+            EIO => "I/O error",
+            ENXIO => "No such device or address",
+            ENOTBLK => "Block device required",
+            ENODEV => "No such device",
+            ENOTDIR => "Not a directory",
+            EISDIR => "Is a directory",
+            ETXTBSY => "Text file busy",
+            ENOEXEC => "Exec format error",
+            EINVAL => "Invalid argument",
+            E2BIG => "Argument list too long",
+            ELOOP => "Symbolic link loop",
+            ENAMETOOLONG => "Filename too long",
+            ENFILE => "Too many open files in system",
+            EMFILE => "No file descriptors available",
+            EBADF => "Bad file descriptor",
+            ECHILD => "No child process",
+            EFAULT => "Bad address",
+            EFBIG => "File too large",
+            EMLINK => "Too many links",
+            ENOLCK => "No locks available",
+            EDEADLK => "Resource deadlock would occur",
+            ENOTRECOVERABLE => "State not recoverable",
+            EOWNERDEAD => "Previous owner died",
+            ECANCELED => "Operation canceled",
+            ENOSYS => "Function not implemented",
+            ENOMSG => "No message of desired type",
+            EIDRM => "Identifier removed",
+            ENOSTR => "Device not a stream",
+            ENODATA => "No data available",
+            ETIME => "Device timeout",
+            ENOSR => "Out of streams resources",
+            ENOLINK => "Link has been severed",
+            EPROTO => "Protocol error",
+            EBADMSG => "Bad message",
+            EBADFD => "File descriptor in bad state",
+            ENOTSOCK => "Not a socket",
+            EDESTADDRREQ => "Destination address required",
+            EMSGSIZE => "Message too large",
+            EPROTOTYPE => "Protocol wrong type for socket",
+            ENOPROTOOPT => "Protocol not available",
+            EPROTONOSUPPORT => "Protocol not supported",
+            ESOCKTNOSUPPORT => "Socket type not supported",
+            EOPNOTSUPP => "Not supported",
+            EPFNOSUPPORT => "Protocol family not supported",
+            EAFNOSUPPORT => "Address family not supported by protocol",
+            EADDRNOTAVAIL => "Address not available",
+            ENETDOWN => "Network is down",
+            ENETUNREACH => "Network unreachable",
+            ENETRESET => "Connection reset by network",
+            ECONNABORTED => "Connection aborted",
+            ENOBUFS => "No buffer space available",
+            EISCONN => "Socket is connected",
+            ENOTCONN => "Socket not connected",
+            ESHUTDOWN => "Cannot send after socket shutdown",
+            EALREADY => "Operation already in progress",
+            EINPROGRESS => "Operation in progress",
+            ESTALE => "Stale file handle",
+            EREMOTEIO => "Remote I/O error",
+            EDQUOT => "Quota exceeded",
+            ENOMEDIUM => "No medium found",
+            EMEDIUMTYPE => "Wrong medium type",
+            EMULTIHOP => "Multihop attempted",
+            ENOKEY => "Required key not available",
+            EKEYEXPIRED => "Key has expired",
+            EKEYREVOKED => "Key has been revoked",
+            EKEYREJECTED => "Key was rejected by service",
+            _ => "No error information",
+        }
+    }
+}
+
+/// A message catalog with glibc's `strerror` descriptions.
+///
+/// [`AxErrorKind`]s are described by their corresponding [`LinuxError`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Glibc;
+
+/// A message catalog with musl's `strerror` descriptions.
+///
+/// [`AxErrorKind`]s are described by their corresponding [`LinuxError`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Musl;
+
+impl MessageCatalog for Glibc {
+    fn kind_message(&self, kind: AxErrorKind) -> Option<&'static str> {
+        Some(kind.to_linux().as_str_glibc())
+    }
+
+    fn linux_message(&self, err: LinuxError) -> Option<&'static str> {
+        Some(err.as_str_glibc())
+    }
+}
+
+impl MessageCatalog for Musl {
+    fn kind_message(&self, kind: AxErrorKind) -> Option<&'static str> {
+        Some(kind.to_linux().as_str_musl())
+    }
+
+    fn linux_message(&self, err: LinuxError) -> Option<&'static str> {
+        Some(err.as_str_musl())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_libc_strings() {
+        for code in 1..=524 {
+            let Ok(err) = LinuxError::try_from(code) else {
+                continue;
+            };
+            if err.is_kernel_internal() {
+                assert_eq!(
+                    err.as_str_glibc().strip_prefix("Unknown error "),
+                    Some(code.to_string().as_str())
+                );
+                assert_eq!(err.as_str_musl(), "No error information");
+            }
+        }
+        assert_eq!(
+            Glibc.kind_message(AxErrorKind::WouldBlock),
+            Some("Resource temporarily unavailable")
+        );
+        assert_eq!(Musl.linux_message(LinuxError::ENOTTY), Some("Not a tty"));
+    }
+}
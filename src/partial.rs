@@ -0,0 +1,134 @@
+//! Results of operations that may fail after partially succeeding.
+
+use crate::{AxError, AxResult, LinuxError, LinuxResult};
+
+/// The result of an operation that may fail after partially succeeding.
+///
+/// Unlike [`AxResult`], an [`AxPartial`] holds both the partial value (e.g.
+/// the number of bytes transferred) and the error that stopped the operation.
+/// This matches the POSIX semantics of short reads and writes, where the byte
+/// count is returned even if an error occurs midway.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{AxError, LinuxError, PartialResult};
+/// let chunks = [Ok(512), Ok(512), Err(AxError::StorageFull), Ok(512)];
+/// let mut res = PartialResult::ok(0);
+/// for chunk in chunks {
+///     res = res.and_then(|_offset| chunk);
+/// }
+/// assert_eq!(res.value, 1024);
+/// assert_eq!(res.error, Some(AxError::StorageFull));
+/// assert_eq!(res.into_linux_result(), Ok(1024));
+///
+/// let res = PartialResult::err(AxError::StorageFull);
+/// assert_eq!(res.into_linux_result(), Err(LinuxError::ENOSPC));
+/// ```
+#[must_use]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct AxPartial<T = usize> {
+    /// The partial value.
+    pub value: T,
+    /// The error that stopped the operation, if any.
+    pub error: Option<AxError>,
+}
+
+/// An [`AxPartial`] holding the number of bytes transferred.
+pub type PartialResult = AxPartial<usize>;
+
+impl<T> AxPartial<T> {
+    /// Creates a new partial result.
+    pub const fn new(value: T, error: Option<AxError>) -> Self {
+        Self { value, error }
+    }
+
+    /// Creates a partial result of a fully successful operation.
+    pub const fn ok(value: T) -> Self {
+        Self { value, error: None }
+    }
+
+    /// Creates a partial result of an operation that failed after producing
+    /// `value`.
+    pub fn failed(value: T, error: impl Into<AxError>) -> Self {
+        Self {
+            value,
+            error: Some(error.into()),
+        }
+    }
+
+    /// Returns `true` if the operation completed without error.
+    pub const fn is_complete(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Maps the partial value, leaving the error untouched.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> AxPartial<U> {
+        AxPartial {
+            value: f(self.value),
+            error: self.error,
+        }
+    }
+
+    /// Splits the partial result into its value and error.
+    pub fn into_parts(self) -> (T, Option<AxError>) {
+        (self.value, self.error)
+    }
+
+    /// Converts into an [`AxResult`], discarding the partial value if an
+    /// error occurred.
+    pub fn into_result(self) -> AxResult<T> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.value),
+        }
+    }
+}
+
+impl AxPartial<usize> {
+    /// Creates a partial result of an operation that failed before
+    /// transferring anything.
+    pub fn err(error: impl Into<AxError>) -> Self {
+        Self::failed(0, error)
+    }
+
+    /// Runs the next step of the operation, unless an error has already
+    /// occurred.
+    ///
+    /// `f` receives the number of bytes transferred so far and returns the
+    /// number of bytes it transferred. On error, the count transferred so far
+    /// is kept.
+    pub fn and_then(self, f: impl FnOnce(usize) -> AxResult<usize>) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        match f(self.value) {
+            Ok(n) => Self::ok(self.value + n),
+            Err(e) => Self::failed(self.value, e),
+        }
+    }
+
+    /// Converts into an [`AxResult`] following the POSIX convention: returns
+    /// the count if it is non-zero, or the error otherwise.
+    pub fn into_transferred(self) -> AxResult<usize> {
+        match self.error {
+            Some(e) if self.value == 0 => Err(e),
+            _ => Ok(self.value),
+        }
+    }
+
+    /// Converts into a [`LinuxResult`] following the POSIX convention: returns
+    /// the count if it is non-zero, or the errno otherwise.
+    pub fn into_linux_result(self) -> LinuxResult<usize> {
+        self.into_transferred().map_err(LinuxError::from)
+    }
+}
+
+impl<T: Default> From<AxResult<T>> for AxPartial<T> {
+    fn from(res: AxResult<T>) -> Self {
+        match res {
+            Ok(value) => Self::ok(value),
+            Err(e) => Self::failed(T::default(), e),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Byte-level conversions for passing errors through shared memory.
+//!
+//! With the `zerocopy` or `bytemuck` feature, [`AxError`], [`AxErrorKind`]
+//! and [`LinuxError`] implement the traits of these crates, and accept the
+//! same codes as `TryFrom<i32>` when read from bytes.
+//!
+//! [`AxErrorKind`]: crate::AxErrorKind
+//! [`LinuxError`]: crate::LinuxError
+
+#![allow(unsafe_code)]
+
+#[cfg(feature = "bytemuck")]
+use crate::AxError;
+
+// SAFETY: `AxError` is a `repr(transparent)` wrapper of a `repr(i32)` enum,
+// which has no padding bytes.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::NoUninit for AxError {}
+
+// SAFETY: `AxError` has the same layout as `i32`, and accepts exactly the
+// codes of valid errors.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::CheckedBitPattern for AxError {
+    type Bits = i32;
+
+    fn is_valid_bit_pattern(bits: &i32) -> bool {
+        AxError::from_code(*bits).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AxError, AxErrorKind, LinuxError};
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_zerocopy() {
+        use zerocopy::{IntoBytes, TryFromBytes};
+
+        for code in -600..600 {
+            let bytes = i32::to_ne_bytes(code);
+            assert_eq!(
+                AxErrorKind::try_read_from_bytes(&bytes).ok(),
+                AxErrorKind::try_from(code).ok()
+            );
+            assert_eq!(
+                LinuxError::try_read_from_bytes(&bytes).ok(),
+                LinuxError::try_from(code).ok()
+            );
+            assert_eq!(
+                AxError::try_read_from_bytes(&bytes).ok(),
+                AxError::try_from(code).ok()
+            );
+        }
+        assert_eq!(
+            AxError::NotFound.as_bytes(),
+            AxErrorKind::NotFound.as_bytes()
+        );
+        assert_eq!(LinuxError::ENOENT.as_bytes(), 2i32.as_bytes());
+
+        #[derive(zerocopy::TryFromBytes, zerocopy::KnownLayout, zerocopy::Immutable)]
+        #[repr(C)]
+        struct Completion {
+            id: u32,
+            error: AxError,
+        }
+        let mut bytes = [0; 8];
+        bytes[4..].copy_from_slice(&(-16i32).to_ne_bytes());
+        let completion = Completion::try_ref_from_bytes(&bytes).unwrap();
+        assert_eq!(completion.error, LinuxError::EBUSY.into());
+        bytes[4..].copy_from_slice(&(-41i32).to_ne_bytes());
+        assert!(Completion::try_ref_from_bytes(&bytes).is_err());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck() {
+        use bytemuck::checked;
+
+        for code in -600..600 {
+            let bytes = i32::to_ne_bytes(code);
+            assert_eq!(
+                checked::try_from_bytes::<AxErrorKind>(&bytes).ok(),
+                AxErrorKind::try_from(code).ok().as_ref()
+            );
+            assert_eq!(
+                checked::try_from_bytes::<LinuxError>(&bytes).ok(),
+                LinuxError::try_from(code).ok().as_ref()
+            );
+            assert_eq!(
+                checked::try_from_bytes::<AxError>(&bytes).ok(),
+                AxError::try_from(code).ok().as_ref()
+            );
+        }
+        assert_eq!(
+            bytemuck::bytes_of(&AxError::from(LinuxError::EIO)),
+            (-5i32).to_ne_bytes()
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Boxed error reports with dynamic messages, sources and context frames.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::{current_context, AxError, AxErrorKind, LinuxError};
+
+type BoxedSource = Box<dyn Error + Send + Sync + 'static>;
+
+struct ReportInner {
+    error: AxError,
+    message: Option<String>,
+    source: Option<BoxedSource>,
+    /// Context frames, from the innermost to the outermost.
+    frames: Vec<String>,
+}
+
+/// A heap-allocated error report.
+///
+/// An [`AxReport`] wraps an [`AxError`] together with an optional formatted
+/// message, an optional source error and a chain of context frames. It can
+/// always be converted back into the underlying [`AxError`], so it
+/// interoperates with [`AxResult`].
+///
+/// The active [`context`] frames are captured when the report is created.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ax_report, AxError, AxErrorKind, AxReport, WrapErr};
+/// fn open(path: &str) -> Result<(), AxReport> {
+///     Err(ax_report!(NotFound, "cannot open {}", path))
+/// }
+///
+/// let report = open("/etc/passwd").wrap_err("loading users").unwrap_err();
+/// assert_eq!(report.error(), AxError::NotFound);
+/// assert_eq!(report.kind(), Some(AxErrorKind::NotFound));
+/// assert_eq!(report.message(), Some("cannot open /etc/passwd"));
+/// # #[cfg(not(feature = "no-descriptions"))]
+/// assert_eq!(
+///     report.to_string(),
+///     "cannot open /etc/passwd\n\
+///      \n\
+///      Caused by:\n    \
+///          0: Entity not found\n\
+///      \n\
+///      Context:\n    \
+///          0: loading users",
+/// );
+/// ```
+///
+/// [`AxResult`]: crate::AxResult
+/// [`context`]: crate::context
+pub struct AxReport(Box<ReportInner>);
+
+impl AxReport {
+    /// Creates a new report from an error, capturing the active context
+    /// frames.
+    pub fn new(error: impl Into<AxError>) -> Self {
+        let mut frames = Vec::new();
+        current_context().for_each(|frame| frames.push(frame.to_string()));
+        frames.reverse();
+        AxReport(Box::new(ReportInner {
+            error: error.into(),
+            message: None,
+            source: None,
+            frames,
+        }))
+    }
+
+    /// Sets the message of this report.
+    pub fn with_message(mut self, message: impl fmt::Display) -> Self {
+        self.0.message = Some(message.to_string());
+        self
+    }
+
+    /// Sets the source error of this report.
+    pub fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> Self {
+        self.0.source = Some(Box::new(source));
+        self
+    }
+
+    /// Adds an outer context frame to this report.
+    pub fn wrap_err(mut self, frame: impl fmt::Display) -> Self {
+        self.0.frames.push(frame.to_string());
+        self
+    }
+
+    /// Returns the underlying error.
+    pub fn error(&self) -> AxError {
+        self.0.error
+    }
+
+    /// Returns the [`AxErrorKind`] of the underlying error, or `None` if it is
+    /// a [`LinuxError`] without a corresponding kind.
+    ///
+    /// This is the same as [`AxError::kind`].
+    pub fn kind(&self) -> Option<AxErrorKind> {
+        self.0.error.kind()
+    }
+
+    /// Returns the message of this report, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.0.message.as_deref()
+    }
+
+    /// Returns the context frames, from the innermost to the outermost.
+    pub fn frames(&self) -> impl Iterator<Item = &str> {
+        self.0.frames.iter().map(String::as_str)
+    }
+}
+
+impl From<AxError> for AxReport {
+    fn from(e: AxError) -> Self {
+        AxReport::new(e)
+    }
+}
+
+impl From<AxErrorKind> for AxReport {
+    fn from(e: AxErrorKind) -> Self {
+        AxReport::new(e)
+    }
+}
+
+impl From<LinuxError> for AxReport {
+    fn from(e: LinuxError) -> Self {
+        AxReport::new(e)
+    }
+}
+
+impl From<AxReport> for AxError {
+    fn from(report: AxReport) -> Self {
+        report.error()
+    }
+}
+
+impl fmt::Display for AxReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = &*self.0;
+        let mut index = 0;
+        match &inner.message {
+            Some(message) => {
+                write!(f, "{message}\n\nCaused by:\n    {index}: {}", inner.error)?;
+                index += 1;
+            }
+            None => write!(f, "{}", inner.error)?,
+        }
+
+        let mut source = inner.source.as_deref().map(|e| e as &dyn Error);
+        while let Some(e) = source {
+            if index == 0 {
+                f.write_str("\n\nCaused by:")?;
+            }
+            write!(f, "\n    {index}: {e}")?;
+            index += 1;
+            source = e.source();
+        }
+
+        for (i, frame) in inner.frames.iter().enumerate() {
+            if i == 0 {
+                f.write_str("\n\nContext:")?;
+            }
+            write!(f, "\n    {i}: {frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for AxReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for AxReport {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0
+            .source
+            .as_deref()
+            .map(|e| e as &(dyn Error + 'static))
+    }
+}
+
+/// Extension methods for adding context to [`Result`]s.
+pub trait WrapErr<T> {
+    /// Converts the error into an [`AxReport`] with an outer context frame.
+    fn wrap_err(self, frame: impl fmt::Display) -> Result<T, AxReport>;
+
+    /// Like [`wrap_err`](WrapErr::wrap_err), but only evaluates the context
+    /// frame on error.
+    fn wrap_err_with<D: fmt::Display>(self, f: impl FnOnce() -> D) -> Result<T, AxReport>;
+}
+
+impl<T, E: Into<AxReport>> WrapErr<T> for Result<T, E> {
+    fn wrap_err(self, frame: impl fmt::Display) -> Result<T, AxReport> {
+        self.map_err(|e| e.into().wrap_err(frame))
+    }
+
+    fn wrap_err_with<D: fmt::Display>(self, f: impl FnOnce() -> D) -> Result<T, AxReport> {
+        self.map_err(|e| e.into().wrap_err(f()))
+    }
+}
+
+/// Constructs an [`AxReport`] with the given error kind and an optional
+/// formatted message.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ax_report, AxError};
+/// let report = ax_report!(BadAddress, "the address {:#x} is unmapped", 0x1000);
+/// assert_eq!(report.error(), AxError::BadAddress);
+/// assert_eq!(report.message(), Some("the address 0x1000 is unmapped"));
+/// ```
+#[macro_export]
+macro_rules! ax_report {
+    ($err: ident) => {
+        $crate::AxReport::new({
+            use $crate::AxErrorKind::*;
+            $err
+        })
+    };
+    ($err: ident, $($arg:tt)+) => {
+        $crate::ax_report!($err).with_message(::core::format_args!($($arg)+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_chain() {
+        let report = AxReport::new(AxError::Io)
+            .with_source(LinuxError::EIO)
+            .wrap_err("flushing inode 42")
+            .wrap_err("syncing /mnt");
+        assert_eq!(report.error(), AxError::Io);
+        assert_eq!(report.kind(), Some(AxErrorKind::Io));
+        assert!(report.source().is_some());
+        assert_eq!(
+            report.frames().collect::<Vec<_>>(),
+            ["flushing inode 42", "syncing /mnt"]
+        );
+        #[cfg(not(feature = "no-descriptions"))]
+        assert_eq!(
+            report.to_string(),
+            "I/O error\n\
+             \n\
+             Caused by:\n    \
+                 0: I/O error\n\
+             \n\
+             Context:\n    \
+                 0: flushing inode 42\n    \
+                 1: syncing /mnt"
+        );
+        assert_eq!(AxError::from(report), AxError::Io);
+    }
+}
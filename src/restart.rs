@@ -0,0 +1,151 @@
+//! Kernel-internal error numbers and system call restart semantics.
+
+use crate::LinuxError;
+
+/// What to do with a system call interrupted by a signal, decided by
+/// [`LinuxError::syscall_restart`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SyscallRestart {
+    /// Return the error to user space.
+    Return(LinuxError),
+    /// Restart the system call by re-executing the system call instruction.
+    Restart,
+    /// Restart the system call through `restart_syscall(2)`.
+    RestartBlock,
+}
+
+impl LinuxError {
+    /// Returns `true` if this is a kernel-internal error number, which must
+    /// never be seen by user programs.
+    pub const fn is_kernel_internal(self) -> bool {
+        use LinuxError::*;
+        matches!(
+            self,
+            ERESTARTSYS
+                | ERESTARTNOINTR
+                | ERESTARTNOHAND
+                | ENOIOCTLCMD
+                | ERESTART_RESTARTBLOCK
+                | EPROBE_DEFER
+                | ENOTSUPP
+        )
+    }
+
+    /// Returns `true` if this error requests the interrupted system call to be
+    /// restarted.
+    pub const fn is_restart(self) -> bool {
+        use LinuxError::*;
+        matches!(
+            self,
+            ERESTARTSYS | ERESTARTNOINTR | ERESTARTNOHAND | ERESTART_RESTARTBLOCK
+        )
+    }
+
+    /// Converts a kernel-internal error number into the one user programs
+    /// should see, and returns other errors unchanged.
+    ///
+    /// Restart requests become [`EINTR`], [`ENOIOCTLCMD`] becomes [`ENOTTY`],
+    /// [`ENOTSUPP`] becomes [`EOPNOTSUPP`] and [`EPROBE_DEFER`] becomes
+    /// [`EAGAIN`].
+    ///
+    /// [`EINTR`]: LinuxError::EINTR
+    /// [`ENOIOCTLCMD`]: LinuxError::ENOIOCTLCMD
+    /// [`ENOTTY`]: LinuxError::ENOTTY
+    /// [`ENOTSUPP`]: LinuxError::ENOTSUPP
+    /// [`EOPNOTSUPP`]: LinuxError::EOPNOTSUPP
+    /// [`EPROBE_DEFER`]: LinuxError::EPROBE_DEFER
+    /// [`EAGAIN`]: LinuxError::EAGAIN
+    pub const fn to_user(self) -> LinuxError {
+        use LinuxError::*;
+        match self {
+            ERESTARTSYS | ERESTARTNOINTR | ERESTARTNOHAND | ERESTART_RESTARTBLOCK => EINTR,
+            ENOIOCTLCMD => ENOTTY,
+            ENOTSUPP => EOPNOTSUPP,
+            EPROBE_DEFER => EAGAIN,
+            _ => self,
+        }
+    }
+
+    /// Decides whether a system call that failed with this error must be
+    /// restarted, following Linux's signal delivery rules.
+    ///
+    /// `handler` is `None` if no signal handler is about to run (e.g. the
+    /// signal was ignored or the task was stopped), or `Some(sa_restart)` if
+    /// a handler is about to run, with `sa_restart` telling whether it was
+    /// installed with `SA_RESTART`.
+    ///
+    /// Errors returned to user space are converted with
+    /// [`to_user`](LinuxError::to_user).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::{LinuxError, SyscallRestart};
+    /// let err = LinuxError::ERESTARTSYS;
+    /// assert_eq!(err.syscall_restart(None), SyscallRestart::Restart);
+    /// assert_eq!(err.syscall_restart(Some(true)), SyscallRestart::Restart);
+    /// assert_eq!(
+    ///     err.syscall_restart(Some(false)),
+    ///     SyscallRestart::Return(LinuxError::EINTR),
+    /// );
+    /// ```
+    pub const fn syscall_restart(self, handler: Option<bool>) -> SyscallRestart {
+        use LinuxError::*;
+        match (self, handler) {
+            (ERESTART_RESTARTBLOCK, None) => SyscallRestart::RestartBlock,
+            (ERESTARTSYS | ERESTARTNOINTR | ERESTARTNOHAND, None) => SyscallRestart::Restart,
+            (ERESTARTNOINTR, Some(_)) | (ERESTARTSYS, Some(true)) => SyscallRestart::Restart,
+            _ => SyscallRestart::Return(self.to_user()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AxErrorKind;
+
+    #[test]
+    fn test_syscall_restart() {
+        use LinuxError::*;
+        for handler in [None, Some(false), Some(true)] {
+            assert_eq!(
+                EINTR.syscall_restart(handler),
+                SyscallRestart::Return(EINTR)
+            );
+            assert_eq!(
+                ERESTARTNOINTR.syscall_restart(handler),
+                SyscallRestart::Restart
+            );
+        }
+        assert_eq!(
+            ERESTARTNOHAND.syscall_restart(None),
+            SyscallRestart::Restart
+        );
+        assert_eq!(
+            ERESTARTNOHAND.syscall_restart(Some(true)),
+            SyscallRestart::Return(EINTR)
+        );
+        assert_eq!(
+            ERESTART_RESTARTBLOCK.syscall_restart(None),
+            SyscallRestart::RestartBlock
+        );
+        assert_eq!(
+            ERESTART_RESTARTBLOCK.syscall_restart(Some(true)),
+            SyscallRestart::Return(EINTR)
+        );
+        assert_eq!(
+            ENOIOCTLCMD.syscall_restart(None),
+            SyscallRestart::Return(ENOTTY)
+        );
+
+        for code in 512..=524 {
+            let Ok(err) = LinuxError::try_from(code) else {
+                continue;
+            };
+            assert!(err.is_kernel_internal());
+            assert!(!err.to_user().is_kernel_internal());
+            assert!(AxErrorKind::try_from(err).is_err());
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Serde support for [`AxError`], [`AxErrorKind`] and [`LinuxError`].
+//!
+//! Errors are serialized as their symbolic names, e.g. `"NotFound"` or
+//! `"ENOENT"`, which stay valid if the codes are renumbered. Use
+//! `#[serde(with = "axerrno::serde::numeric")]` to serialize the codes
+//! instead.
+//!
+//! Deserialization accepts only known names and codes; codes are checked with
+//! `TryFrom<i32>`. Human-readable formats accept both names and codes.
+//!
+//! # Examples
+//!
+//! ```
+//! use axerrno::{AxError, LinuxError};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct TestResult {
+//!     error: AxError,
+//!     #[serde(with = "axerrno::serde::numeric")]
+//!     errno: LinuxError,
+//! }
+//!
+//! let result = TestResult {
+//!     error: AxError::NotFound,
+//!     errno: LinuxError::ENOENT,
+//! };
+//! let json = serde_json::to_string(&result).unwrap();
+//! assert_eq!(json, r#"{"error":"NotFound","errno":2}"#);
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, Unexpected, Visitor};
+use ::serde::{Deserialize, Serialize, Serializer};
+
+use crate::{AxError, AxErrorKind, LinuxError};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for crate::AxError {}
+    impl Sealed for crate::AxErrorKind {}
+    impl Sealed for crate::LinuxError {}
+}
+
+/// An error type that can be serialized by name or by code.
+///
+/// Implemented for [`AxError`], [`AxErrorKind`] and [`LinuxError`].
+pub trait ErrorCode: Copy + TryFrom<i32> + sealed::Sealed {
+    /// Describes the expected input in deserialization errors.
+    #[doc(hidden)]
+    const EXPECTING: &'static str;
+
+    /// Returns the code of the error.
+    fn code(self) -> i32;
+
+    /// Returns the symbolic name of the error.
+    fn name(self) -> &'static str;
+
+    /// Returns the error with the given symbolic name.
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+impl ErrorCode for AxError {
+    const EXPECTING: &'static str = "an AxErrorKind or LinuxError name or an AxError code";
+
+    fn code(self) -> i32 {
+        self.code()
+    }
+
+    fn name(self) -> &'static str {
+        self.name()
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::from_name(name)
+    }
+}
+
+impl ErrorCode for AxErrorKind {
+    const EXPECTING: &'static str = "an AxErrorKind name or code";
+
+    fn code(self) -> i32 {
+        self.code()
+    }
+
+    fn name(self) -> &'static str {
+        self.name()
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::from_name(name)
+    }
+}
+
+impl ErrorCode for LinuxError {
+    const EXPECTING: &'static str = "a LinuxError name or code";
+
+    fn code(self) -> i32 {
+        self.code()
+    }
+
+    fn name(self) -> &'static str {
+        self.name()
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::from_name(name)
+    }
+}
+
+struct ErrorVisitor<T>(PhantomData<T>);
+
+impl<T: ErrorCode> Visitor<'_> for ErrorVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_name(v).ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        i32::try_from(v)
+            .ok()
+            .and_then(|code| T::try_from(code).ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        i32::try_from(v)
+            .ok()
+            .and_then(|code| T::try_from(code).ok())
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+}
+
+fn serialize_name<T: ErrorCode, S: Serializer>(value: T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(value.name())
+}
+
+fn deserialize_name<'de, T: ErrorCode, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(ErrorVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_str(ErrorVisitor(PhantomData))
+    }
+}
+
+/// Serializes errors as their codes, for use with
+/// `#[serde(with = "axerrno::serde::numeric")]`.
+///
+/// [`AxError`]s are serialized as [`AxError::code`], so errors converted from
+/// [`LinuxError`]s become negative.
+pub mod numeric {
+    use core::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{ErrorCode, ErrorVisitor};
+
+    /// Serializes an error as its code.
+    pub fn serialize<T: ErrorCode, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(value.code())
+    }
+
+    /// Deserializes an error from its code.
+    pub fn deserialize<'de, T: ErrorCode, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_i32(ErrorVisitor(PhantomData))
+    }
+}
+
+macro_rules! impl_serde {
+    ($($ty:ty),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_name(*self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_name(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde!(AxError, AxErrorKind, LinuxError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let err = AxError::from(LinuxError::ECHILD);
+        assert_eq!(serde_json::to_string(&err).unwrap(), r#""ECHILD""#);
+        assert_eq!(serde_json::from_str::<AxError>(r#""ECHILD""#).unwrap(), err);
+        assert_eq!(serde_json::from_str::<AxError>("-10").unwrap(), err);
+        assert_eq!(
+            serde_json::from_str::<AxErrorKind>(r#""NotFound""#).unwrap(),
+            AxErrorKind::NotFound
+        );
+        assert!(serde_json::from_str::<AxErrorKind>(r#""ENOENT""#).is_err());
+        assert!(serde_json::from_str::<AxErrorKind>("0").is_err());
+        assert!(serde_json::from_str::<LinuxError>("41").is_err());
+        assert!(serde_json::from_str::<LinuxError>("4294967298").is_err());
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Numeric {
+            #[serde(with = "numeric")]
+            kind: AxErrorKind,
+            #[serde(with = "numeric")]
+            err: AxError,
+        }
+        let value = Numeric {
+            kind: AxErrorKind::TimedOut,
+            err,
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"kind":38,"err":-10}"#);
+        assert_eq!(serde_json::from_str::<Numeric>(&json).unwrap(), value);
+        assert!(serde_json::from_str::<Numeric>(r#"{"kind":44,"err":-10}"#).is_err());
+    }
+}
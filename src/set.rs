@@ -0,0 +1,242 @@
+//! Sets of error kinds.
+
+use core::fmt;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+use strum::EnumCount;
+
+use crate::{AxError, AxErrorKind};
+
+const _: () = assert!(AxErrorKind::COUNT < u64::BITS as usize);
+
+/// A set of [`AxErrorKind`]s, usable in `const` context.
+///
+/// Membership of [`AxError`]s and [`LinuxError`]s is tested through their
+/// canonical [`AxErrorKind`], so e.g. [`LinuxError::EAGAIN`] is in any set
+/// containing [`AxErrorKind::WouldBlock`].
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ax_error_set, AxError, AxErrorKind, AxErrorSet, LinuxError};
+/// const RETRY_ON: AxErrorSet = ax_error_set!(Interrupted, WouldBlock);
+///
+/// assert!(RETRY_ON.contains(AxErrorKind::Interrupted));
+/// assert!(RETRY_ON.contains_error(AxError::WouldBlock));
+/// assert!(RETRY_ON.contains_error(LinuxError::EAGAIN));
+/// assert!(!RETRY_ON.contains_error(LinuxError::ENOENT));
+/// assert!(!RETRY_ON.contains_error(LinuxError::ECHILD));
+/// ```
+///
+/// [`LinuxError`]: crate::LinuxError
+/// [`LinuxError::EAGAIN`]: crate::LinuxError::EAGAIN
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct AxErrorSet(u64);
+
+impl AxErrorSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self(0);
+
+    /// The set of all error kinds.
+    pub const ALL: Self = Self(((1 << AxErrorKind::COUNT) - 1) << 1);
+
+    /// Creates a set containing the given error kinds.
+    pub const fn from_kinds(kinds: &[AxErrorKind]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < kinds.len() {
+            set = set.with(kinds[i]);
+            i += 1;
+        }
+        set
+    }
+
+    const fn bit(kind: AxErrorKind) -> u64 {
+        1 << kind.code()
+    }
+
+    /// Returns this set with `kind` added.
+    pub const fn with(self, kind: AxErrorKind) -> Self {
+        Self(self.0 | Self::bit(kind))
+    }
+
+    /// Returns this set with `kind` removed.
+    pub const fn without(self, kind: AxErrorKind) -> Self {
+        Self(self.0 & !Self::bit(kind))
+    }
+
+    /// Returns the union of two sets.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the intersection of two sets.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the error kinds in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns the error kinds not in this set.
+    pub const fn complement(self) -> Self {
+        Self(Self::ALL.0 & !self.0)
+    }
+
+    /// Returns `true` if the set contains `kind`.
+    pub const fn contains(self, kind: AxErrorKind) -> bool {
+        self.0 & Self::bit(kind) != 0
+    }
+
+    /// Returns `true` if the set contains the canonical kind of `err`.
+    ///
+    /// Errors without a corresponding [`AxErrorKind`] are never contained.
+    pub fn contains_error(self, err: impl Into<AxError>) -> bool {
+        err.into().kind().is_some_and(|kind| self.contains(kind))
+    }
+
+    /// Returns `true` if every error kind in `self` is also in `other`.
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Returns `true` if the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of error kinds in the set.
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns an iterator over the error kinds in the set, in code order.
+    pub fn iter(self) -> impl Iterator<Item = AxErrorKind> {
+        (1..=AxErrorKind::COUNT as i32)
+            .filter(move |code| self.0 & (1 << code) != 0)
+            .filter_map(|code| AxErrorKind::try_from(code).ok())
+    }
+}
+
+impl From<AxErrorKind> for AxErrorSet {
+    fn from(kind: AxErrorKind) -> Self {
+        Self::EMPTY.with(kind)
+    }
+}
+
+impl FromIterator<AxErrorKind> for AxErrorSet {
+    fn from_iter<I: IntoIterator<Item = AxErrorKind>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl Extend<AxErrorKind> for AxErrorSet {
+    fn extend<I: IntoIterator<Item = AxErrorKind>>(&mut self, iter: I) {
+        *self = iter.into_iter().fold(*self, Self::with);
+    }
+}
+
+impl BitOr for AxErrorSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for AxErrorSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for AxErrorSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for AxErrorSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Sub for AxErrorSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for AxErrorSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for AxErrorSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl fmt::Debug for AxErrorSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Constructs an [`AxErrorSet`] from a list of [`AxErrorKind`] variant
+/// names, in `const` context.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{ax_error_set, AxErrorKind, AxErrorSet};
+/// const IGNORED: AxErrorSet = ax_error_set!(AlreadyExists);
+/// assert_eq!(IGNORED, AxErrorSet::from(AxErrorKind::AlreadyExists));
+/// assert_eq!(ax_error_set!(), AxErrorSet::EMPTY);
+/// ```
+///
+/// [`AxErrorKind`]: crate::AxErrorKind
+#[macro_export]
+macro_rules! ax_error_set {
+    ($($kind: ident),* $(,)?) => {
+        $crate::AxErrorSet::from_kinds(&[$($crate::AxErrorKind::$kind),*])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_ops() {
+        assert_eq!(AxErrorSet::ALL.len(), AxErrorKind::COUNT);
+        assert_eq!(AxErrorSet::ALL.iter().count(), AxErrorKind::COUNT);
+        assert!(AxErrorSet::ALL.contains(AxErrorKind::AddrInUse));
+        assert!(AxErrorSet::ALL.contains(AxErrorKind::WriteZero));
+        assert_eq!(!AxErrorSet::ALL, AxErrorSet::EMPTY);
+
+        let a = ax_error_set!(NotFound, Io, WouldBlock);
+        let b = ax_error_set!(Io, TimedOut);
+        assert_eq!(a | b, ax_error_set!(NotFound, Io, WouldBlock, TimedOut));
+        assert_eq!(a & b, ax_error_set!(Io));
+        assert_eq!(a - b, ax_error_set!(NotFound, WouldBlock));
+        assert!((a & b).is_subset(b));
+        assert_eq!(!a & a, AxErrorSet::EMPTY);
+        assert_eq!((!a).len(), AxErrorKind::COUNT - 3);
+        assert_eq!(
+            a.iter().collect::<AxErrorSet>(),
+            a.without(AxErrorKind::TimedOut)
+        );
+    }
+}
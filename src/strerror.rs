@@ -0,0 +1,188 @@
+//! Formatting of errors into byte buffers without `core::fmt`.
+//!
+//! These functions only copy bytes, so they are safe to call from panic
+//! handlers, NMI handlers, signal handlers or an early boot console, where the
+//! formatting machinery must be avoided.
+
+use crate::{AxError, AxErrorData, AxErrorKind, LinuxError};
+
+/// A cursor copying bytes into a buffer, silently truncating the output.
+struct ByteWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+    truncated: bool,
+}
+
+impl<'a> ByteWriter<'a> {
+    fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            buf,
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        let n = bytes.len().min(self.buf.len() - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+        self.len += n;
+        self.truncated |= n < bytes.len();
+    }
+
+    fn push_i32(&mut self, value: i32) {
+        let mut digits = [0; 11];
+        let mut pos = digits.len();
+        let mut n = value.unsigned_abs();
+        loop {
+            pos -= 1;
+            digits[pos] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        if value < 0 {
+            pos -= 1;
+            digits[pos] = b'-';
+        }
+        self.push(&digits[pos..]);
+    }
+
+    fn push_name_desc(&mut self, name: &str, desc: &str) {
+        self.push(name.as_bytes());
+        if !cfg!(feature = "no-descriptions") {
+            self.push(b": ");
+            self.push(desc.as_bytes());
+        }
+    }
+}
+
+impl AxErrorKind {
+    /// Writes the name and description of the error kind into `buf`, e.g.
+    /// `NotFound: Entity not found`, without going through `core::fmt`.
+    ///
+    /// The output is truncated to fit into `buf`. Returns the number of bytes
+    /// written.
+    pub fn write_to(&self, buf: &mut [u8]) -> usize {
+        let mut w = ByteWriter::new(buf);
+        w.push_name_desc(self.name(), self.as_str());
+        w.len
+    }
+}
+
+impl LinuxError {
+    /// Writes the name and description of the error into `buf`, e.g.
+    /// `ENOENT: No such file or directory`, without going through
+    /// `core::fmt`.
+    ///
+    /// The output is truncated to fit into `buf`. Returns the number of bytes
+    /// written.
+    pub fn write_to(&self, buf: &mut [u8]) -> usize {
+        let mut w = ByteWriter::new(buf);
+        w.push_name_desc(self.name(), self.as_str());
+        w.len
+    }
+}
+
+impl AxError {
+    /// Writes the name and description of the error into `buf`, e.g.
+    /// `NotFound: Entity not found`, without going through `core::fmt`.
+    ///
+    /// The output is truncated to fit into `buf`. Returns the number of bytes
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use axerrno::AxError;
+    /// let mut buf = [0; 64];
+    /// let len = AxError::NotFound.write_to(&mut buf);
+    /// # #[cfg(not(feature = "no-descriptions"))]
+    /// assert_eq!(&buf[..len], b"NotFound: Entity not found");
+    ///
+    /// let mut buf = [0; 8];
+    /// let len = AxError::NotFound.write_to(&mut buf);
+    /// assert_eq!(&buf[..len], b"NotFound");
+    /// ```
+    pub fn write_to(&self, buf: &mut [u8]) -> usize {
+        match self.data() {
+            AxErrorData::Ax(kind) => kind.write_to(buf),
+            AxErrorData::Linux(e) => e.write_to(buf),
+        }
+    }
+}
+
+/// Writes the description of the error number `errnum` into `buf`, like the
+/// XSI-compliant `strerror_r`.
+///
+/// `0` is described as `Success`, like in C libraries. The output is always
+/// NUL-terminated if `buf` is not empty. Returns `0` on success, `EINVAL` if
+/// `errnum` is not a known error number (the output is then
+/// `Unknown error <errnum>`), or `ERANGE` if the output was truncated.
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::strerror_r;
+/// let mut buf = [0; 32];
+/// assert_eq!(strerror_r(2, &mut buf), 0);
+/// # #[cfg(not(feature = "no-descriptions"))]
+/// assert_eq!(&buf[..26], b"No such file or directory\0");
+/// ```
+pub fn strerror_r(errnum: i32, buf: &mut [u8]) -> i32 {
+    let Some((last, buf)) = buf.split_last_mut() else {
+        return LinuxError::ERANGE.code();
+    };
+    let mut w = ByteWriter::new(buf);
+    let ret = match LinuxError::try_from(errnum) {
+        _ if errnum == 0 => {
+            w.push(b"Success");
+            0
+        }
+        Ok(e) => {
+            w.push(e.as_str().as_bytes());
+            0
+        }
+        Err(_) => {
+            w.push(b"Unknown error ");
+            w.push_i32(errnum);
+            LinuxError::EINVAL.code()
+        }
+    };
+    let (len, truncated) = (w.len, w.truncated);
+    if len < buf.len() {
+        buf[len] = 0;
+    } else {
+        *last = 0;
+    }
+    if truncated {
+        LinuxError::ERANGE.code()
+    } else {
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strerror_r() {
+        let mut buf = [0xff; 32];
+        assert_eq!(strerror_r(0, &mut buf), 0);
+        assert_eq!(&buf[..8], b"Success\0");
+        assert_eq!(strerror_r(-7, &mut buf), LinuxError::EINVAL.code());
+        assert_eq!(&buf[..17], b"Unknown error -7\0");
+        assert_eq!(strerror_r(i32::MIN, &mut buf), LinuxError::EINVAL.code());
+        assert_eq!(&buf[..26], b"Unknown error -2147483648\0");
+
+        let mut buf = [0xff; 5];
+        assert_eq!(strerror_r(1, &mut buf), LinuxError::ERANGE.code());
+        assert_eq!(buf[4], 0);
+        assert_eq!(strerror_r(1, &mut []), LinuxError::ERANGE.code());
+
+        let mut buf = [0; 64];
+        let len = AxError::from(LinuxError::EPERM).write_to(&mut buf);
+        assert!(buf[..len].starts_with(b"EPERM"));
+    }
+}
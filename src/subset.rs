@@ -0,0 +1,99 @@
+//! Restricted subsets of [`AxErrorKind`].
+//!
+//! [`AxErrorKind`]: crate::AxErrorKind
+
+/// Defines an enum restricted to a subset of [`AxErrorKind`] variants.
+///
+/// The generated enum has the same variant names and codes as the
+/// corresponding [`AxErrorKind`] variants, and implements:
+///
+/// - `From<Subset>` for [`AxErrorKind`], which also gives infallible
+///   conversions into [`AxError`] and [`LinuxError`];
+/// - `TryFrom<AxErrorKind>` and `TryFrom<AxError>` for `Subset`, which fail
+///   with the original error if it is not in the subset;
+/// - [`Display`](core::fmt::Display) and [`Error`](core::error::Error).
+///
+/// # Examples
+///
+/// ```
+/// # use axerrno::{error_subset, AxError, LinuxError};
+/// error_subset! {
+///     /// Errors returned by directory lookups.
+///     pub LookupError: NotFound, NotADirectory, PermissionDenied
+/// }
+///
+/// fn lookup(_name: &str) -> Result<u64, LookupError> {
+///     Err(LookupError::NotFound)
+/// }
+///
+/// let err = lookup("foo").unwrap_err();
+/// assert_eq!(AxError::from(err), AxError::NotFound);
+/// assert_eq!(LinuxError::from(err), LinuxError::ENOENT);
+/// assert_eq!(
+///     LookupError::try_from(AxError::from(LinuxError::EACCES)),
+///     Ok(LookupError::PermissionDenied),
+/// );
+/// assert_eq!(LookupError::try_from(AxError::Io), Err(AxError::Io));
+/// ```
+///
+/// [`AxErrorKind`]: crate::AxErrorKind
+/// [`AxError`]: crate::AxError
+/// [`LinuxError`]: crate::LinuxError
+#[macro_export]
+macro_rules! error_subset {
+    ($(#[$meta:meta])* $vis:vis $name:ident: $($kind:ident),+ $(,)?) => {
+        $(#[$meta])*
+        #[repr(i32)]
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        $vis enum $name {
+            $(
+                #[doc = concat!("The `AxErrorKind::", stringify!($kind), "` error.")]
+                $kind = $crate::AxErrorKind::$kind as i32,
+            )+
+        }
+
+        impl ::core::convert::From<$name> for $crate::AxErrorKind {
+            fn from(e: $name) -> Self {
+                match e {
+                    $($name::$kind => $crate::AxErrorKind::$kind,)+
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::LinuxError {
+            fn from(e: $name) -> Self {
+                $crate::AxErrorKind::from(e).into()
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::AxErrorKind> for $name {
+            type Error = $crate::AxErrorKind;
+
+            fn try_from(e: $crate::AxErrorKind) -> ::core::result::Result<Self, Self::Error> {
+                match e {
+                    $($crate::AxErrorKind::$kind => Ok($name::$kind),)+
+                    _ => Err(e),
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::AxError> for $name {
+            type Error = $crate::AxError;
+
+            fn try_from(e: $crate::AxError) -> ::core::result::Result<Self, Self::Error> {
+                $crate::AxErrorKind::try_from(e)
+                    .ok()
+                    .and_then(|kind| $name::try_from(kind).ok())
+                    .ok_or(e)
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&$crate::AxErrorKind::from(*self), f)
+            }
+        }
+
+        impl ::core::error::Error for $name {}
+    };
+}
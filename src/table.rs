@@ -0,0 +1,107 @@
+//! Machine-readable export of the error tables.
+//!
+//! [`write_table`] dumps every [`AxErrorKind`] and [`LinuxError`] with its
+//! code, name, description and corresponding error of the other type, as
+//! JSON, CSV or Markdown.
+//!
+//! The same tables can be generated at build time by setting the
+//! `AXERRNO_TABLE_DIR` environment variable to a directory, where the build
+//! script then writes `axerrno.json`, `axerrno.csv` and `axerrno.md`.
+//!
+//! # Examples
+//!
+//! ```
+//! use axerrno::table::{self, TableFormat};
+//!
+//! let mut csv = String::new();
+//! table::write_table(&mut csv, TableFormat::Csv).unwrap();
+//! assert!(csv.starts_with("table,code,name,description,mapped_code,mapped_name\n"));
+//! # #[cfg(not(feature = "no-descriptions"))]
+//! assert!(csv.contains("\nAxErrorKind,30,NotFound,Entity not found,2,ENOENT\n"));
+//! # #[cfg(not(feature = "no-descriptions"))]
+//! assert!(csv.contains("\nLinuxError,2,ENOENT,No such file or directory,30,NotFound\n"));
+//! ```
+
+mod format;
+
+use core::fmt;
+
+pub use format::TableFormat;
+use format::{Row, Table};
+
+use crate::{AxErrorKind, LinuxError};
+
+/// Writes the [`AxErrorKind`] and [`LinuxError`] tables in the given format.
+///
+/// The descriptions are the built-in English ones returned by `as_str()`.
+/// With the `no-descriptions` feature, they are `null` in JSON and empty in
+/// CSV and Markdown.
+pub fn write_table(w: &mut impl fmt::Write, format: TableFormat) -> fmt::Result {
+    let mut kinds = (1..).map_while(AxErrorKind::from_code).map(|kind| {
+        let e = kind.to_linux();
+        Row {
+            code: kind.code(),
+            name: kind.name(),
+            description: (!cfg!(feature = "no-descriptions")).then(|| kind.as_str()),
+            mapped: Some((e.code(), e.name())),
+        }
+    });
+    let mut linux = (1..=4095).filter_map(LinuxError::from_code).map(|e| Row {
+        code: e.code(),
+        name: e.name(),
+        description: (!cfg!(feature = "no-descriptions")).then(|| e.as_str()),
+        mapped: AxErrorKind::from_linux(e).map(|kind| (kind.code(), kind.name())),
+    });
+    format::write_tables(
+        w,
+        format,
+        [
+            Table {
+                name: "AxErrorKind",
+                key: "ax_error_kinds",
+                mapped_name: "LinuxError",
+                mapped_key: "linux_error",
+                rows: &mut kinds,
+            },
+            Table {
+                name: "LinuxError",
+                key: "linux_errors",
+                mapped_name: "AxErrorKind",
+                mapped_key: "ax_error_kind",
+                rows: &mut linux,
+            },
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_table() {
+        let mut json = String::new();
+        write_table(&mut json, TableFormat::Json).unwrap();
+        assert!(json.starts_with("{\n  \"ax_error_kinds\": [\n    {\"code\": 1, "));
+        assert!(json.contains("\n  ],\n  \"linux_errors\": [\n"));
+        #[cfg(not(feature = "no-descriptions"))]
+        assert!(json.contains(
+            r#"{"code": 11, "name": "EAGAIN", "description": "Try again", "ax_error_kind": {"code": 42, "name": "WouldBlock"}}"#
+        ));
+        #[cfg(not(feature = "no-descriptions"))]
+        assert!(json.contains(r#""name": "EPROBE_DEFER", "description": "Driver requests probe retry", "ax_error_kind": null}"#));
+        #[cfg(feature = "no-descriptions")]
+        assert!(json.contains(
+            r#"{"code": 11, "name": "EAGAIN", "description": null, "ax_error_kind": {"code": 42, "name": "WouldBlock"}}"#
+        ));
+
+        let mut md = String::new();
+        write_table(&mut md, TableFormat::Markdown).unwrap();
+        assert!(md.starts_with("## AxErrorKind\n"));
+        assert!(md.contains("\n\n## LinuxError\n"));
+        #[cfg(not(feature = "no-descriptions"))]
+        assert!(md.contains("| 23 | `NoMemory` | Out of memory | `ENOMEM` (12) |\n"));
+        #[cfg(feature = "no-descriptions")]
+        assert!(md.contains("| 23 | `NoMemory` |  | `ENOMEM` (12) |\n"));
+    }
+}
@@ -0,0 +1,180 @@
+//! Table writers, shared with the build script.
+
+use core::fmt::{self, Write};
+
+/// The format of an exported error table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TableFormat {
+    /// A JSON object with an `ax_error_kinds` and a `linux_errors` array.
+    Json,
+    /// CSV with a header row, and a `table` column telling the two tables
+    /// apart.
+    Csv,
+    /// Two Markdown tables, each under a heading.
+    Markdown,
+}
+
+impl TableFormat {
+    /// All formats.
+    pub const ALL: [TableFormat; 3] = [TableFormat::Json, TableFormat::Csv, TableFormat::Markdown];
+
+    /// Returns the usual file extension of the format, e.g. `"json"`.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            TableFormat::Json => "json",
+            TableFormat::Csv => "csv",
+            TableFormat::Markdown => "md",
+        }
+    }
+}
+
+/// A row of an error table.
+pub struct Row<'a> {
+    pub code: i32,
+    pub name: &'a str,
+    /// The description, or `None` if descriptions are compiled out.
+    pub description: Option<&'a str>,
+    /// The code and name of the corresponding error in the other table.
+    pub mapped: Option<(i32, &'a str)>,
+}
+
+/// A table of errors of one type.
+pub struct Table<'a, I> {
+    /// The type name, e.g. `"LinuxError"`.
+    pub name: &'a str,
+    /// The key of the table in JSON.
+    pub key: &'a str,
+    /// The type name of the mapped errors.
+    pub mapped_name: &'a str,
+    /// The key of the mapped error in JSON.
+    pub mapped_key: &'a str,
+    pub rows: I,
+}
+
+/// Writes the `AxErrorKind` and `LinuxError` tables in the given format.
+pub fn write_tables<'a>(
+    w: &mut impl Write,
+    format: TableFormat,
+    tables: [Table<'a, &mut dyn Iterator<Item = Row<'a>>>; 2],
+) -> fmt::Result {
+    match format {
+        TableFormat::Json => write_json(w, tables),
+        TableFormat::Csv => write_csv(w, tables),
+        TableFormat::Markdown => write_markdown(w, tables),
+    }
+}
+
+fn write_json_str(w: &mut impl Write, s: &str) -> fmt::Result {
+    w.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => w.write_str("\\\"")?,
+            '\\' => w.write_str("\\\\")?,
+            c if c.is_control() => write!(w, "\\u{:04x}", c as u32)?,
+            c => w.write_char(c)?,
+        }
+    }
+    w.write_char('"')
+}
+
+fn write_json<'a>(
+    w: &mut impl Write,
+    tables: [Table<'a, &mut dyn Iterator<Item = Row<'a>>>; 2],
+) -> fmt::Result {
+    w.write_str("{")?;
+    for (i, table) in tables.into_iter().enumerate() {
+        let sep = if i == 0 { "" } else { "," };
+        write!(w, "{sep}\n  \"{}\": [", table.key)?;
+        for (j, row) in table.rows.enumerate() {
+            let sep = if j == 0 { "" } else { "," };
+            write!(w, "{sep}\n    {{\"code\": {}, \"name\": ", row.code)?;
+            write_json_str(w, row.name)?;
+            w.write_str(", \"description\": ")?;
+            match row.description {
+                Some(description) => write_json_str(w, description)?,
+                None => w.write_str("null")?,
+            }
+            write!(w, ", \"{}\": ", table.mapped_key)?;
+            match row.mapped {
+                Some((code, name)) => {
+                    write!(w, "{{\"code\": {code}, \"name\": ")?;
+                    write_json_str(w, name)?;
+                    w.write_str("}}")?;
+                }
+                None => w.write_str("null}")?,
+            }
+        }
+        w.write_str("\n  ]")?;
+    }
+    w.write_str("\n}\n")
+}
+
+fn write_csv_field(w: &mut impl Write, s: &str) -> fmt::Result {
+    if s.contains([',', '"', '\n', '\r']) {
+        w.write_char('"')?;
+        for c in s.chars() {
+            if c == '"' {
+                w.write_char('"')?;
+            }
+            w.write_char(c)?;
+        }
+        w.write_char('"')
+    } else {
+        w.write_str(s)
+    }
+}
+
+fn write_csv<'a>(
+    w: &mut impl Write,
+    tables: [Table<'a, &mut dyn Iterator<Item = Row<'a>>>; 2],
+) -> fmt::Result {
+    w.write_str("table,code,name,description,mapped_code,mapped_name\n")?;
+    for table in tables {
+        for row in table.rows {
+            write!(w, "{},{},", table.name, row.code)?;
+            write_csv_field(w, row.name)?;
+            w.write_char(',')?;
+            write_csv_field(w, row.description.unwrap_or(""))?;
+            match row.mapped {
+                Some((code, name)) => writeln!(w, ",{code},{name}")?,
+                None => w.write_str(",,\n")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_markdown_cell(w: &mut impl Write, s: &str) -> fmt::Result {
+    for c in s.chars() {
+        if c == '|' {
+            w.write_char('\\')?;
+        }
+        w.write_char(c)?;
+    }
+    Ok(())
+}
+
+fn write_markdown<'a>(
+    w: &mut impl Write,
+    tables: [Table<'a, &mut dyn Iterator<Item = Row<'a>>>; 2],
+) -> fmt::Result {
+    for (i, table) in tables.into_iter().enumerate() {
+        if i > 0 {
+            w.write_char('\n')?;
+        }
+        write!(
+            w,
+            "## {}\n\n| Code | Name | Description | {} |\n| ---: | --- | --- | --- |\n",
+            table.name, table.mapped_name
+        )?;
+        for row in table.rows {
+            write!(w, "| {} | `{}` | ", row.code, row.name)?;
+            write_markdown_cell(w, row.description.unwrap_or(""))?;
+            match row.mapped {
+                Some((code, name)) => writeln!(w, " | `{name}` ({code}) |")?,
+                None => w.write_str(" | - |\n")?,
+            }
+        }
+    }
+    Ok(())
+}